@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::de::value::MapDeserializer;
+
+use crate::specs::{ImportError, RawCar};
+
+/// A column that goes by a different name in older exporter versions.
+struct Alias {
+    /// Last exporter version (as written in the `Exporter Version` column,
+    /// e.g. 20220803) using `old`.
+    until: u32,
+    old: &'static str,
+    new: &'static str,
+}
+
+/// Known column renames, mapped onto the names RawCar expects. When an
+/// exporter update renames a column, add the old name here with the last
+/// version that used it instead of changing RawCar. Empty until a rename
+/// shows up in a real export.
+const ALIASES: &[Alias] = &[];
+
+/// Turns the header row of an export into the column names RawCar expects.
+pub fn map_headers(headers: &csv::StringRecord, exporter_version: u32) -> Vec<String> {
+    map_headers_with(headers, exporter_version, ALIASES)
+}
+
+fn map_headers_with(headers: &csv::StringRecord, exporter_version: u32, aliases: &[Alias]) -> Vec<String> {
+    // The exporter writes some column names twice, the second one becomes "<name> 2"
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    headers.iter().map(|h| h.trim()).map(|header| {
+        let header = aliases.iter()
+            .find(|a| exporter_version <= a.until && a.old.eq_ignore_ascii_case(header))
+            .map(|a| a.new)
+            .unwrap_or(header);
+        let count = seen.entry(header).or_insert(0);
+        *count += 1;
+        match count {
+            1 => String::from(header),
+            n => format!("{} {}", header, n),
+        }
+    }).collect()
+}
+
+/// Deserializes a RawCar from one row of an export. Returns the car along with
+/// warnings about unknown and missing optional columns.
+pub fn deserialize_row(headers: &csv::StringRecord, record: &csv::StringRecord) -> Result<(RawCar, Vec<String>), ImportError> {
+    // Exports without a readable version are treated as the newest, so no alias applies
+    let exporter_version = headers.iter()
+        .position(|h| h.trim() == "Exporter Version")
+        .and_then(|i| record.get(i))
+        .and_then(|v| v.trim().parse::<u32>().ok())
+        .unwrap_or(u32::MAX);
+    let headers = map_headers(headers, exporter_version);
+
+    let known = RawCar::columns();
+    let mut warnings = Vec::new();
+    for header in &headers {
+        if !known.contains(&header.as_str()) {
+            warnings.push(format!("unknown column \"{}\"", header));
+        }
+    }
+    for column in known {
+        if !headers.iter().any(|h| h == column) {
+            warnings.push(format!("missing column \"{}\"", column));
+        }
+    }
+
+    let values = headers.into_iter().zip(record.iter().map(String::from));
+    let raw = RawCar::deserialize(MapDeserializer::<_, ColumnError>::new(values)).map_err(|e| match e {
+        ColumnError::Missing(column) => ImportError::MissingColumn(String::from(column)),
+        ColumnError::Other(e) => ImportError::BadCsv(e),
+    })?;
+    // Missing required columns turn into an error above, so only optional ones are left to warn about
+    Ok((raw, warnings))
+}
+
+#[derive(Debug)]
+enum ColumnError {
+    Missing(&'static str),
+    Other(String),
+}
+
+impl std::fmt::Display for ColumnError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::result::Result<(), std::fmt::Error> {
+        match self {
+            Self::Missing(column) => write!(f, "missing column \"{}\"", column),
+            Self::Other(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for ColumnError {}
+
+impl serde::de::Error for ColumnError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Self::Other(msg.to_string())
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        Self::Missing(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_ALIASES: &[Alias] = &[
+        Alias { until: 20220803, old: "Old Name", new: "New Name" },
+    ];
+
+    #[test]
+    fn duplicate_columns_are_numbered() {
+        let headers = csv::StringRecord::from(vec!["Front Wheel Diameter", "Rear Wheel Diameter", "Front Wheel Diameter", " Front Wheel Diameter "]);
+        assert_eq!(map_headers(&headers, 20220803), vec!["Front Wheel Diameter", "Rear Wheel Diameter", "Front Wheel Diameter 2", "Front Wheel Diameter 3"]);
+    }
+
+    #[test]
+    fn aliases_apply_up_to_their_exporter_version() {
+        let headers = csv::StringRecord::from(vec!["old name", "Other"]);
+        assert_eq!(map_headers_with(&headers, 20220101, TEST_ALIASES), vec!["New Name", "Other"]);
+        assert_eq!(map_headers_with(&headers, 20220803, TEST_ALIASES), vec!["New Name", "Other"]);
+        assert_eq!(map_headers_with(&headers, 20220804, TEST_ALIASES), vec!["old name", "Other"]);
+    }
+
+    #[test]
+    fn alias_and_current_name_count_as_duplicates() {
+        let headers = csv::StringRecord::from(vec!["New Name", "Old Name"]);
+        assert_eq!(map_headers_with(&headers, 20220101, TEST_ALIASES), vec!["New Name", "New Name 2"]);
+    }
+}
@@ -0,0 +1,126 @@
+use anyhow::{Result, bail};
+
+/// Text encodings an exported csv can show up in. Automation writes UTF-16LE,
+/// but files re-saved by Excel or a text editor can end up in any of the others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+    Windows1252,
+}
+
+/// Characters for bytes 0x80..=0x9F in Windows-1252. Everything else maps
+/// straight onto the matching Latin-1 code point.
+const WINDOWS_1252: [char; 32] = [
+    '\u{20AC}', '\u{0081}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{008D}', '\u{017D}', '\u{008F}',
+    '\u{0090}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}', '\u{0153}', '\u{009D}', '\u{017E}', '\u{0178}',
+];
+
+/// Detects the encoding of `data`, returning it along with the length of the
+/// byte order mark to skip.
+pub fn detect(data: &[u8]) -> (Encoding, usize) {
+    if data.starts_with(&[0xEF, 0xBB, 0xBF]) { return (Encoding::Utf8, 3); }
+    if data.starts_with(&[0xFF, 0xFE]) { return (Encoding::Utf16Le, 2); }
+    if data.starts_with(&[0xFE, 0xFF]) { return (Encoding::Utf16Be, 2); }
+
+    // Without a BOM, UTF-16 text that is mostly ASCII has a zero byte in every
+    // other position: odd positions for little endian, even ones for big endian.
+    let sample = &data[..data.len().min(1024)];
+    let pairs = sample.len() / 2;
+    if pairs > 0 {
+        let even_zeros = sample.iter().step_by(2).filter(|b| **b == 0).count();
+        let odd_zeros = sample.iter().skip(1).step_by(2).filter(|b| **b == 0).count();
+        if odd_zeros * 2 > pairs && even_zeros * 10 < pairs { return (Encoding::Utf16Le, 0); }
+        if even_zeros * 2 > pairs && odd_zeros * 10 < pairs { return (Encoding::Utf16Be, 0); }
+    }
+
+    if std::str::from_utf8(data).is_ok() {
+        (Encoding::Utf8, 0)
+    } else {
+        (Encoding::Windows1252, 0)
+    }
+}
+
+/// Decodes an exported csv file into a string, whatever encoding it is in.
+pub fn decode(data: &[u8]) -> Result<String> {
+    let (encoding, bom) = detect(data);
+    let data = &data[bom..];
+    Ok(match encoding {
+        Encoding::Utf8 => String::from_utf8(data.to_vec())?,
+        Encoding::Utf16Le => decode_utf16(data, u16::from_le_bytes)?,
+        Encoding::Utf16Be => decode_utf16(data, u16::from_be_bytes)?,
+        Encoding::Windows1252 => data.iter().map(|b| match b {
+            0x80..=0x9F => WINDOWS_1252[(b - 0x80) as usize],
+            _ => *b as char,
+        }).collect(),
+    })
+}
+
+fn decode_utf16(data: &[u8], to_u16: fn([u8; 2]) -> u16) -> Result<String> {
+    if !data.len().is_multiple_of(2) {
+        bail!("utf-16 data has an odd number of bytes");
+    }
+    let units: Vec<u16> = data.chunks_exact(2).map(|c| to_u16([c[0], c[1]])).collect();
+    Ok(String::from_utf16(&units)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "Car Name,Trim Name\nSucchiare,WRC\n";
+
+    fn utf16(text: &str, to_bytes: fn(u16) -> [u8; 2]) -> Vec<u8> {
+        text.encode_utf16().flat_map(to_bytes).collect()
+    }
+
+    #[test]
+    fn utf16_le() {
+        let data = utf16(TEXT, u16::to_le_bytes);
+        assert_eq!(detect(&data), (Encoding::Utf16Le, 0));
+        assert_eq!(decode(&data).unwrap(), TEXT);
+
+        let data = [vec![0xFF, 0xFE], data].concat();
+        assert_eq!(detect(&data), (Encoding::Utf16Le, 2));
+        assert_eq!(decode(&data).unwrap(), TEXT);
+    }
+
+    #[test]
+    fn utf16_be() {
+        let data = utf16(TEXT, u16::to_be_bytes);
+        assert_eq!(detect(&data), (Encoding::Utf16Be, 0));
+        assert_eq!(decode(&data).unwrap(), TEXT);
+
+        let data = [vec![0xFE, 0xFF], data].concat();
+        assert_eq!(detect(&data), (Encoding::Utf16Be, 2));
+        assert_eq!(decode(&data).unwrap(), TEXT);
+    }
+
+    #[test]
+    fn utf8() {
+        assert_eq!(detect(TEXT.as_bytes()), (Encoding::Utf8, 0));
+        assert_eq!(decode(TEXT.as_bytes()).unwrap(), TEXT);
+
+        let data = [&[0xEF, 0xBB, 0xBF], TEXT.as_bytes()].concat();
+        assert_eq!(detect(&data), (Encoding::Utf8, 3));
+        assert_eq!(decode(&data).unwrap(), TEXT);
+    }
+
+    #[test]
+    fn utf16_odd_length() {
+        let mut data = utf16(TEXT, u16::to_le_bytes);
+        data.push(b'x');
+        assert!(decode(&data).is_err());
+        assert!(decode(&[0xFF, 0xFE, b'a']).is_err());
+    }
+
+    #[test]
+    fn windows_1252() {
+        let data = b"Price,\x80100\nCaf\xE9,\x93quoted\x94\n";
+        assert_eq!(detect(data), (Encoding::Windows1252, 0));
+        assert_eq!(decode(data).unwrap(), "Price,\u{20AC}100\nCaf\u{E9},\u{201C}quoted\u{201D}\n");
+    }
+}
@@ -0,0 +1,20 @@
+//! Checks cars exported from Automation against a set of challenge regulations.
+//!
+//! ```no_run
+//! use regulation_checker::{Car, Regulations};
+//!
+//! let regulations: Regulations = std::fs::read_to_string("regulations.toml")?.parse()?;
+//! let car = Car::from_csv_reader(std::fs::File::open("car.csv")?)?;
+//! let report = regulations.check_car(&car);
+//! println!("{}", report);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+pub mod columns;
+pub mod decode;
+mod fields;
+pub mod specs;
+pub mod regulations;
+
+pub use specs::{Car, RawCar, ImportError};
+pub use regulations::{Regulations, CheckReport, Violation, Severity};
@@ -1,63 +1,202 @@
-mod specs;
-mod regulations;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 
-fn main() {
-    println!("Loading regulations...");
-    let regulations = regulations::Regulations::load().expect("Failed to load regulations!");
+use clap::{Parser, Subcommand, ValueEnum};
 
-    println!("Scanning ./cars...");
+use regulation_checker::{decode, Car, CheckReport, ImportError, Regulations};
+
+#[derive(Debug, Parser)]
+#[command(about = "Checks Automation car exports against a set of challenge regulations")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Regulations file to check the cars against
+    #[arg(long, global = true, default_value = "regulations.toml")]
+    regulations: PathBuf,
+    /// Folder containing one subfolder per submitted car
+    #[arg(long, global = true, default_value = "cars")]
+    cars: PathBuf,
+    /// Output file (defaults to result.txt or result.json for check and car_dump.txt for dump)
+    #[arg(long, global = true)]
+    out: Option<PathBuf>,
+    /// Format of the check results
+    #[arg(long, global = true, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Only print errors
+    #[arg(long, short, global = true)]
+    quiet: bool,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Check every car against the regulations (default)
+    Check,
+    /// Dump every imported car to a text file
+    Dump,
+    /// Write a utf-8 copy of every exported csv file next to it
+    Convert,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+macro_rules! info {
+    ($cli:expr, $($arg:tt)*) => {
+        if !$cli.quiet { println!($($arg)*); }
+    };
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command.as_ref().unwrap_or(&Command::Check) {
+        Command::Check => check(&cli),
+        Command::Dump => dump(&cli).map(|_| true),
+        Command::Convert => scan(&cli).and_then(|dirs| convert(&cli, &dirs)).map(|_| true),
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(e) => {
+            eprintln!("Error: {:#}", e);
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn scan(cli: &Cli) -> anyhow::Result<Vec<String>> {
+    info!(cli, "Scanning {}...", cli.cars.display());
     let mut dirs = Vec::new();
-    for entry in std::fs::read_dir("cars").expect("Cannot read ./cars! Does the folder exist?") {
-        if let Ok(entry) = entry {
-            let path = entry.path();
-            if path.is_dir() {
-                dirs.push(path.display().to_string());
-            }
+    for entry in std::fs::read_dir(&cli.cars).map_err(|e| anyhow::anyhow!("Cannot read {}! Does the folder exist? ({})", cli.cars.display(), e))?.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            dirs.push(path.display().to_string());
         }
     }
+    dirs.sort();
+    Ok(dirs)
+}
 
-    println!("Converting all csv files to utf-8...");
-    for dir in &dirs {
-        let name = dir.replace("cars/", "").replace(r"cars\", "");
+fn dir_name(dir: &str) -> String {
+    Path::new(dir).file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default()
+}
+
+fn convert(cli: &Cli, dirs: &[String]) -> anyhow::Result<()> {
+    info!(cli, "Converting all csv files to utf-8...");
+    for dir in dirs {
+        let name = dir_name(dir);
         let p = format!("{}/{}.csv", dir, name);
         let p2 = format!("{}/{}_utf8.csv", dir, name);
-        let data = std::fs::read(&p).expect("Failed to read csv file!");
-        let (_prefix, utf16, _suffix) = unsafe { data.align_to::<u16>() };
-        let utf8 = String::from_utf16(&utf16[..]).expect("Failed to turn utf16 data into utf8! Please message me if this happens");
-        std::fs::write(p2, utf8).expect("Failed to write csv!");
-    }
-
-    println!("Cars found: {}", dirs.len());
-    let mut cars = Vec::new();
-    for dir in &dirs {
-        match specs::Car::from_directory(dir) {
-            Ok(car) => cars.push(car),
-            Err(e) => println!("{}", e),
+        let converted = std::fs::read(&p).map_err(anyhow::Error::from)
+            .and_then(|data| decode::decode(&data))
+            .and_then(|utf8| Ok(std::fs::write(&p2, utf8)?));
+        if let Err(e) = converted {
+            eprintln!("{}: could not be converted: {}", name, e);
         }
     }
+    Ok(())
+}
+
+/// Every car folder along with the imported car, or the reason it could not be imported.
+fn load_cars(cli: &Cli) -> anyhow::Result<Vec<(String, Result<Car, ImportError>)>> {
+    let dirs = scan(cli)?;
+
+    info!(cli, "Cars found: {}", dirs.len());
+    Ok(dirs.into_iter().map(|dir| {
+        let car = Car::from_directory(&dir);
+        (dir, car)
+    }).collect())
+}
+
+fn dump(cli: &Cli) -> anyhow::Result<()> {
+    let cars = load_cars(cli)?;
+    let out = cli.out.clone().unwrap_or_else(|| PathBuf::from("car_dump.txt"));
 
-    println!("Dumping all cars to car_dump.txt...");
+    info!(cli, "Dumping all cars to {}...", out.display());
     let mut dump = String::new();
-    for car in &cars {
-        dump.push_str(&format!("{:#?}", car));
-        dump.push('\n');
-        dump.push('\n');
+    for (dir, car) in &cars {
+        match car {
+            Ok(car) => {
+                dump.push_str(&format!("{:#?}", car));
+                dump.push('\n');
+                dump.push('\n');
+            },
+            Err(e) => eprintln!("{}: could not be imported: {}", dir_name(dir), e),
+        }
     }
-    std::fs::write("car_dump.txt", dump).expect("Failed to write car dump!");
+    std::fs::write(&out, dump).map_err(|e| anyhow::anyhow!("Failed to write car dump {}! ({})", out.display(), e))?;
+    Ok(())
+}
+
+fn check(cli: &Cli) -> anyhow::Result<bool> {
+    info!(cli, "Loading regulations...");
+    let regulations = Regulations::load(&cli.regulations).map_err(|e| anyhow::anyhow!("Failed to load {}! {}", cli.regulations.display(), e))?;
+
+    let cars = load_cars(cli)?;
+    let results: Vec<Option<CheckReport>> = cars.iter().map(|(_, car)| {
+        car.as_ref().ok().map(|car| regulations.check_car(car))
+    }).collect();
 
-    let mut max_width = 0;
-    let mut results = Vec::new();
-    for car in &cars {
-        max_width = max_width.max(car.car_name.len());
-        results.push(regulations.check_car(&car));
+    let (default_out, output) = match cli.format {
+        Format::Text => ("result.txt", results_text(&cars, &results)),
+        Format::Json => ("result.json", results_json(&cars, &results)?),
+    };
+    let out = cli.out.clone().unwrap_or_else(|| PathBuf::from(default_out));
+    info!(cli, "{}", output);
+    std::fs::write(&out, output).map_err(|e| anyhow::anyhow!("Failed to write output {}! ({})", out.display(), e))?;
+
+    Ok(results.iter().all(|r| r.as_ref().map(|r| r.passed()).unwrap_or(false)))
+}
+
+fn car_name(dir: &str, car: &Result<Car, ImportError>) -> String {
+    match car {
+        Ok(car) => format!("{} ({})", car.car_name, car.trim_name),
+        Err(_) => dir_name(dir),
     }
+}
+
+fn results_text(cars: &[(String, Result<Car, ImportError>)], results: &[Option<CheckReport>]) -> String {
+    let max_width = cars.iter().map(|(dir, car)| car_name(dir, car).len()).max().unwrap_or(0);
     let mut result_text = String::from("========================================\n Results\n========================================\n");
-    for (i, car) in cars.iter().enumerate() {
-        let result = &results[i];
-        result_text.push_str(&format!("{: <width$}", format!("{}... ", car.car_name), width = max_width + 4));
-        result_text.push_str(&format!("{:?}", result));
+    for ((dir, car), result) in cars.iter().zip(results) {
+        result_text.push_str(&format!("{: <width$}", format!("{}... ", car_name(dir, car)), width = max_width + 4));
+        match car {
+            Ok(_) => result_text.push_str(&result.as_ref().map(|r| r.to_string()).unwrap_or_default()),
+            Err(e) => result_text.push_str(&format!("could not be imported: {}", e)),
+        }
         result_text.push('\n');
     }
-    println!("{}", result_text);
-    std::fs::write("result.txt", result_text).expect("Failed to write output!");
+    result_text
+}
+
+fn results_json(cars: &[(String, Result<Car, ImportError>)], results: &[Option<CheckReport>]) -> anyhow::Result<String> {
+    let entries: Vec<serde_json::Value> = cars.iter().zip(results).map(|((dir, car), result)| {
+        match (car, result) {
+            (Ok(car), Some(result)) => serde_json::json!({
+                "name": car.car_name,
+                "trim": car.trim_name,
+                "directory": dir,
+                "passed": result.passed(),
+                "violations": result.violations,
+            }),
+            (Err(e), _) => serde_json::json!({
+                "name": dir_name(dir),
+                "directory": dir,
+                "passed": false,
+                "error": format!("could not be imported: {}", e),
+                "violations": [],
+            }),
+            (Ok(car), None) => serde_json::json!({
+                "name": car.car_name,
+                "trim": car.trim_name,
+                "directory": dir,
+                "passed": false,
+                "violations": [],
+            }),
+        }
+    }).collect();
+    Ok(serde_json::to_string_pretty(&entries)?)
 }
@@ -0,0 +1,79 @@
+use serde::Deserialize;
+
+use crate::specs::Car;
+use super::{Violation, check_banned, check_max, check_min};
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Aero {
+    /// Banned undertrays, e.g. `["Race"]`; cars without one export "None".
+    pub banned_undertrays: Option<Vec<String>>,
+    pub banned_active_aero: Option<Vec<String>>,
+    /// `false` bans any active aero, i.e. anything but "None".
+    pub allow_active_aero: Option<bool>,
+    pub front_max_wing_angle: Option<f32>,
+    pub rear_max_wing_angle: Option<f32>,
+
+    /// Front plus rear downforce.
+    pub max_downforce: Option<f32>,
+    pub front_max_downforce: Option<f32>,
+    pub rear_max_downforce: Option<f32>,
+
+    pub cooling: Option<Cooling>,
+}
+
+/// `[aero.cooling]`, reported as `aero.cooling.<rule>`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Cooling {
+    pub banned_active_cooling: Option<Vec<String>>,
+    /// Minimum of the cooling slider, 0 to 1.
+    pub min_amount: Option<f32>,
+    /// Cap on the engine's Required Cooling.
+    pub max_required: Option<f32>,
+    /// The cooling slider has to be at least `Required Cooling / approx_required_per_amount`.
+    /// This is only an approximation picked by the organiser: the game doesn't export
+    /// how much cooling a car actually has, nor how the slider relates to Required Cooling.
+    pub approx_required_per_amount: Option<f32>,
+}
+
+impl Aero {
+    pub fn check_car(&self, car: &Car) -> Vec<Violation> {
+        let mut errs = Vec::new();
+        check_banned(&mut errs, "aero", "banned_undertrays", &self.banned_undertrays, &car.undertray, "undertray");
+        check_banned(&mut errs, "aero", "banned_active_aero", &self.banned_active_aero, &car.active_aero, "active aero");
+        if self.allow_active_aero == Some(false) && !car.active_aero.trim().eq_ignore_ascii_case("None") {
+            errs.push(Violation::new("aero", "allow_active_aero", "None", &car.active_aero, format!("active aero {} is not allowed", car.active_aero)));
+        }
+        check_max(&mut errs, "aero", "front_max_wing_angle", self.front_max_wing_angle, car.front_wing_angle, "front wing angle");
+        check_max(&mut errs, "aero", "rear_max_wing_angle", self.rear_max_wing_angle, car.rear_wing_angle, "rear wing angle");
+
+        let downforce = car.front_downforce.zip(car.rear_downforce).map(|(f, r)| f + r);
+        check_max(&mut errs, "aero", "max_downforce", self.max_downforce, downforce, "total downforce");
+        check_max(&mut errs, "aero", "front_max_downforce", self.front_max_downforce, car.front_downforce, "front downforce");
+        check_max(&mut errs, "aero", "rear_max_downforce", self.rear_max_downforce, car.rear_downforce, "rear downforce");
+
+        if let Some(cooling) = &self.cooling {
+            errs.extend(cooling.check_car(car));
+        }
+        errs
+    }
+}
+
+impl Cooling {
+    pub fn check_car(&self, car: &Car) -> Vec<Violation> {
+        let mut errs = Vec::new();
+        check_banned(&mut errs, "aero", "cooling.banned_active_cooling", &self.banned_active_cooling, &car.active_cooling, "active cooling");
+        check_min(&mut errs, "aero", "cooling.min_amount", self.min_amount, car.cooling_amount, "cooling amount");
+        check_max(&mut errs, "aero", "cooling.max_required", self.max_required, car.required_cooling, "required cooling");
+        if let Some(approx_required_per_amount) = self.approx_required_per_amount {
+            let needed = car.required_cooling.map(|r| (r / approx_required_per_amount * 100.0).round() / 100.0);
+            match (needed, car.cooling_amount) {
+                (Some(needed), Some(amount)) if amount < needed => errs.push(Violation::new("aero", "cooling.approx_required_per_amount", format!(">= {}", needed), amount, format!("cooling amount {} is below the approximate minimum of {} for the engine's required cooling", amount, needed))),
+                (Some(_), Some(_)) => {},
+                _ => errs.push(Violation::unmeasured("aero", "cooling.approx_required_per_amount", "cooling amount or required cooling")),
+            }
+        }
+        errs
+    }
+}
@@ -0,0 +1,79 @@
+use anyhow::{Result, bail};
+use serde::Deserialize;
+
+use crate::specs::Car;
+use super::{Violation, check_allowed, check_banned, check_max};
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Aspiration {
+    /// Aspiration types the engine may use, e.g. `["Naturally Aspirated", "Turbo"]`.
+    pub allowed_types: Option<Vec<String>>,
+    /// Banned aspiration types or turbo/supercharger items, e.g. `["Twin", "Supercharger"]`.
+    pub banned_types: Option<Vec<String>>,
+    pub banned_boost_control: Option<Vec<String>>,
+    pub max_boost: Option<f32>,
+    pub max_compressor_size: Option<f32>,
+    pub max_turbine_size: Option<f32>,
+    pub max_intercooler_size: Option<f32>,
+
+    /// Forced induction engines count as `displacement * displacement_factor`
+    /// against `max_equivalent_displacement`, e.g. 1.7 for rally rules.
+    pub displacement_factor: Option<f32>,
+    pub max_equivalent_displacement: Option<usize>,
+}
+
+impl Aspiration {
+    /// Makes sure `displacement_factor` has a limit to apply to.
+    pub fn validate(&self) -> Result<()> {
+        if self.displacement_factor.is_some() && self.max_equivalent_displacement.is_none() {
+            bail!("aspiration.displacement_factor needs aspiration.max_equivalent_displacement");
+        }
+        Ok(())
+    }
+
+    pub fn check_car(&self, car: &Car) -> Vec<Violation> {
+        let mut errs = Vec::new();
+        check_allowed(&mut errs, "aspiration", "allowed_types", &self.allowed_types, &car.aspiration, "aspiration");
+        if let Some(banned_types) = &self.banned_types {
+            let parts = [&car.aspiration, &car.aspiration_item_1, &car.aspiration_sub_item_1, &car.aspiration_item_2, &car.aspiration_sub_item_2];
+            let banned: Vec<&str> = parts.into_iter()
+                .filter(|part| banned_types.iter().any(|t| part.to_lowercase().contains(&t.trim().to_lowercase())))
+                .map(|part| part.as_str())
+                .collect();
+            if !banned.is_empty() {
+                errs.push(Violation::banned("aspiration", "banned_types", banned_types, &banned.join(", "), "aspiration"));
+            }
+        }
+        check_banned(&mut errs, "aspiration", "banned_boost_control", &self.banned_boost_control, &car.boost_control, "boost control");
+        check_max(&mut errs, "aspiration", "max_boost", self.max_boost, car.peak_boost, "peak boost");
+        // Only the largest of the two turbos is reported, naturally aspirated engines have none
+        let compressor_size = [car.compressor_size_1, car.compressor_size_2].into_iter().flatten().reduce(f32::max);
+        if let (Some(max_compressor_size), Some(size)) = (self.max_compressor_size, compressor_size) {
+            if size > max_compressor_size {
+                errs.push(Violation::max("aspiration", "max_compressor_size", max_compressor_size, size, "largest compressor size"));
+            }
+        }
+        let turbine_size = [car.turbine_size_1, car.turbine_size_2].into_iter().flatten().reduce(f32::max);
+        if let (Some(max_turbine_size), Some(size)) = (self.max_turbine_size, turbine_size) {
+            if size > max_turbine_size {
+                errs.push(Violation::max("aspiration", "max_turbine_size", max_turbine_size, size, "largest turbine size"));
+            }
+        }
+        if let Some(max_intercooler_size) = self.max_intercooler_size {
+            if let Some(size) = car.intercooler_size {
+                if car.has_turbo() && size > max_intercooler_size {
+                    errs.push(Violation::max("aspiration", "max_intercooler_size", max_intercooler_size, size, "intercooler size"));
+                }
+            }
+        }
+        if let Some(max_equivalent_displacement) = self.max_equivalent_displacement {
+            let factor = if car.has_turbo() { self.displacement_factor.unwrap_or(1.0) } else { 1.0 };
+            let equivalent = (car.displacement as f32 * factor).round() as usize;
+            if equivalent > max_equivalent_displacement {
+                errs.push(Violation::max("aspiration", "max_equivalent_displacement", max_equivalent_displacement, equivalent, "equivalent displacement"));
+            }
+        }
+        errs
+    }
+}
@@ -0,0 +1,57 @@
+use serde::Deserialize;
+
+use crate::specs::Car;
+use super::{Violation, check_allowed, check_banned, check_max, check_min};
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Brakes {
+    pub front_allowed_types: Option<Vec<String>>,
+    pub front_banned_types: Option<Vec<String>>,
+    pub front_max_caliper_pistons: Option<usize>,
+    pub front_min_diameter: Option<f32>,
+    pub front_max_diameter: Option<f32>,
+    /// Pad type slider, 0 for the softest street pads up to 1 for race pads.
+    pub front_max_pad_type: Option<f32>,
+    pub front_min_force: Option<f32>,
+    pub front_max_force: Option<f32>,
+
+    pub rear_allowed_types: Option<Vec<String>>,
+    pub rear_banned_types: Option<Vec<String>>,
+    pub rear_max_caliper_pistons: Option<usize>,
+    pub rear_min_diameter: Option<f32>,
+    pub rear_max_diameter: Option<f32>,
+    pub rear_max_pad_type: Option<f32>,
+    pub rear_min_force: Option<f32>,
+    pub rear_max_force: Option<f32>,
+
+    pub min_braking_distance: Option<f32>,
+    pub max_braking_distance: Option<f32>,
+}
+
+impl Brakes {
+    pub fn check_car(&self, car: &Car) -> Vec<Violation> {
+        let mut errs = Vec::new();
+        check_allowed(&mut errs, "brakes", "front_allowed_types", &self.front_allowed_types, &car.front_brake_type, "front brake type");
+        check_banned(&mut errs, "brakes", "front_banned_types", &self.front_banned_types, &car.front_brake_type, "front brake type");
+        check_max(&mut errs, "brakes", "front_max_caliper_pistons", self.front_max_caliper_pistons, car.front_caliper_pistons, "front caliper pistons");
+        check_min(&mut errs, "brakes", "front_min_diameter", self.front_min_diameter, car.front_brake_diameter, "front brake diameter");
+        check_max(&mut errs, "brakes", "front_max_diameter", self.front_max_diameter, car.front_brake_diameter, "front brake diameter");
+        check_max(&mut errs, "brakes", "front_max_pad_type", self.front_max_pad_type, car.front_brake_pad_type, "front brake pad type");
+        check_min(&mut errs, "brakes", "front_min_force", self.front_min_force, car.front_brake_force, "front brake force");
+        check_max(&mut errs, "brakes", "front_max_force", self.front_max_force, car.front_brake_force, "front brake force");
+
+        check_allowed(&mut errs, "brakes", "rear_allowed_types", &self.rear_allowed_types, &car.rear_brake_type, "rear brake type");
+        check_banned(&mut errs, "brakes", "rear_banned_types", &self.rear_banned_types, &car.rear_brake_type, "rear brake type");
+        check_max(&mut errs, "brakes", "rear_max_caliper_pistons", self.rear_max_caliper_pistons, car.rear_caliper_pistons, "rear caliper pistons");
+        check_min(&mut errs, "brakes", "rear_min_diameter", self.rear_min_diameter, car.rear_brake_diameter, "rear brake diameter");
+        check_max(&mut errs, "brakes", "rear_max_diameter", self.rear_max_diameter, car.rear_brake_diameter, "rear brake diameter");
+        check_max(&mut errs, "brakes", "rear_max_pad_type", self.rear_max_pad_type, car.rear_brake_pad_type, "rear brake pad type");
+        check_min(&mut errs, "brakes", "rear_min_force", self.rear_min_force, car.rear_brake_force, "rear brake force");
+        check_max(&mut errs, "brakes", "rear_max_force", self.rear_max_force, car.rear_brake_force, "rear brake force");
+
+        check_min(&mut errs, "brakes", "min_braking_distance", self.min_braking_distance, car.braking_distance, "braking distance");
+        check_max(&mut errs, "brakes", "max_braking_distance", self.max_braking_distance, car.braking_distance, "braking distance");
+        errs
+    }
+}
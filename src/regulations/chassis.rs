@@ -1,28 +1,66 @@
-use anyhow::Result;
 use serde::Deserialize;
 
 use crate::specs::Car;
-use super::CheckError;
+use super::{Violation, check_allowed, check_banned, check_min};
 
+/// Body and chassis rules, written as `[chassis]` or `[body]`.
 #[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct Chassis {
     pub banned_materials: Option<Vec<String>>,
+    pub allowed_materials: Option<Vec<String>>,
+    pub allowed_types: Option<Vec<String>>,
+    pub banned_types: Option<Vec<String>>,
+    pub allowed_panel_materials: Option<Vec<String>>,
+    pub banned_panel_materials: Option<Vec<String>>,
+
+    pub allowed_body_types: Option<Vec<String>>,
+    pub banned_body_types: Option<Vec<String>>,
+    pub min_doors: Option<usize>,
+    /// Minimum full size seats in each row, front to back, e.g. `[2, 3]`.
+    pub min_full_seats: Option<Vec<usize>>,
+    /// Minimum small seats in each row, front to back.
+    pub min_small_seats: Option<Vec<usize>>,
+
+    pub min_passenger_volume: Option<f32>,
+    pub min_cargo_volume: Option<f32>,
+    pub min_tow_weight: Option<f32>,
 }
 
 impl Chassis {
-    pub fn check_car(&self, car: &Car) -> Result<()> {
+    pub fn check_car(&self, car: &Car) -> Vec<Violation> {
         let mut errs = Vec::new();
         if let Some(banned_materials) = &self.banned_materials {
             for mat in banned_materials {
                 if mat.trim() == car.chassis_material.trim() {
-                    errs.push(format!("banned chassis material {}", car.chassis_material));
+                    errs.push(Violation::banned("chassis", "banned_materials", banned_materials, &car.chassis_material, "chassis material"));
                 }
             }
         }
-        if errs.len() > 0 {
-            return Err(CheckError::ErrChassis(errs).into());
+        check_allowed(&mut errs, "chassis", "allowed_materials", &self.allowed_materials, &car.chassis_material, "chassis material");
+        check_allowed(&mut errs, "chassis", "allowed_types", &self.allowed_types, &car.chassis_type, "chassis type");
+        check_banned(&mut errs, "chassis", "banned_types", &self.banned_types, &car.chassis_type, "chassis type");
+        check_allowed(&mut errs, "chassis", "allowed_panel_materials", &self.allowed_panel_materials, &car.panel_material, "panel material");
+        check_banned(&mut errs, "chassis", "banned_panel_materials", &self.banned_panel_materials, &car.panel_material, "panel material");
+
+        check_allowed(&mut errs, "chassis", "allowed_body_types", &self.allowed_body_types, &car.body_type, "body type");
+        check_banned(&mut errs, "chassis", "banned_body_types", &self.banned_body_types, &car.body_type, "body type");
+        check_min(&mut errs, "chassis", "min_doors", self.min_doors, car.doors, "doors");
+
+        let full_seats = [car.full_1st_row_seats, car.full_2nd_row_seats, car.full_3rd_row_seats];
+        let small_seats = [car.small_1st_row_seats, car.small_2nd_row_seats, car.small_3rd_row_seats];
+        for (row, min) in self.min_full_seats.iter().flatten().enumerate() {
+            let seats = full_seats.get(row).copied().unwrap_or(Some(0));
+            check_min(&mut errs, "chassis", "min_full_seats", Some(*min), seats, &format!("row {} full seats", row + 1));
+        }
+        for (row, min) in self.min_small_seats.iter().flatten().enumerate() {
+            let seats = small_seats.get(row).copied().unwrap_or(Some(0));
+            check_min(&mut errs, "chassis", "min_small_seats", Some(*min), seats, &format!("row {} small seats", row + 1));
         }
 
-        Ok(())
+        check_min(&mut errs, "chassis", "min_passenger_volume", self.min_passenger_volume, car.passenger_volume, "passenger volume");
+        check_min(&mut errs, "chassis", "min_cargo_volume", self.min_cargo_volume, car.cargo_volume, "cargo volume");
+        check_min(&mut errs, "chassis", "min_tow_weight", self.min_tow_weight, car.tow_weight, "tow weight");
+        errs
     }
 }
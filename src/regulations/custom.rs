@@ -0,0 +1,103 @@
+use std::collections::{HashMap, HashSet};
+use anyhow::{Result, bail};
+use serde::Deserialize;
+
+use crate::fields::serialized_fields;
+use crate::specs::{Car, RawCar};
+use super::Violation;
+use super::expr::{Expression, Value};
+
+/// A `[[custom]]` rule: the car passes if `expr` is true.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Custom {
+    pub name: String,
+    pub expr: Expression,
+    pub message: Option<String>,
+}
+
+/// Makes sure custom rule names are unique and every expression only refers to
+/// fields of `Car` or raw columns that exist.
+pub fn validate_custom(custom: &[Custom]) -> Result<()> {
+    let known: Vec<String> = serialized_fields::<Car>().into_iter().chain(serialized_fields::<RawCar>()).collect();
+    let mut names = HashSet::new();
+    for rule in custom {
+        if !names.insert(rule.name.trim()) {
+            bail!("custom rule `{}` is defined more than once", rule.name);
+        }
+        for field in rule.expr.fields() {
+            if !known.iter().any(|k| k == field) {
+                bail!("unknown field `{}` in custom rule `{}`", field, rule.name);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Every value an expression can refer to: the fields of `Car` by name, and
+/// the raw csv columns by their RawCar field name. Values the export has no
+/// figure for ("???") are left out.
+pub fn custom_fields(car: &Car) -> HashMap<String, Value> {
+    let mut fields = HashMap::new();
+    for (k, v) in &car.raw {
+        let value = match v.trim() {
+            "" | "???" => continue,
+            n => match n.parse::<f64>() {
+                Ok(n) => Value::Number(n),
+                Err(_) => Value::Text(v.clone()),
+            },
+        };
+        fields.insert(k.clone(), value);
+    }
+    if let Ok(serde_json::Value::Object(map)) = serde_json::to_value(car) {
+        for (k, v) in map {
+            let value = match v {
+                // Every float in Car is an f32, which serde_json widens so 3.9 becomes
+                // 3.9000000953. Going through its decimal form keeps 3.9.
+                serde_json::Value::Number(n) if n.is_f64() => {
+                    let n = n.as_f64().unwrap_or_default() as f32;
+                    Value::Number(n.to_string().parse().unwrap_or_default())
+                },
+                serde_json::Value::Number(n) => Value::Number(n.as_f64().unwrap_or_default()),
+                serde_json::Value::String(s) => Value::Text(s),
+                serde_json::Value::Bool(b) => Value::Bool(b),
+                serde_json::Value::Null => {
+                    fields.remove(&k);
+                    continue;
+                },
+                _ => continue,
+            };
+            fields.insert(k, value);
+        }
+    }
+    fields
+}
+
+impl Custom {
+    pub fn check_car(&self, fields: &HashMap<String, Value>) -> Vec<Violation> {
+        let mut errs = Vec::new();
+        let missing: Vec<&str> = self.expr.fields().into_iter().filter(|f| !fields.contains_key(*f)).collect();
+        if !missing.is_empty() {
+            errs.push(Violation::unmeasured("custom", &self.name, &missing.join(", ")));
+            return errs;
+        }
+        let actual = self.expr.fields().iter()
+            .map(|f| format!("{} = {}", f, fields[*f]))
+            .collect::<Vec<String>>()
+            .join(", ");
+        match self.expr.eval(fields) {
+            Ok(Value::Bool(true)) => {},
+            Ok(Value::Bool(false)) => {
+                let message = self.message.clone().unwrap_or_else(|| format!("{} failed", self.name));
+                errs.push(Violation::new("custom", &self.name, &self.expr.source, actual, message));
+            },
+            Ok(other) => {
+                errs.push(Violation::new("custom", &self.name, &self.expr.source, other, format!("{} is not a true/false expression", self.name)));
+            },
+            Err(e) => {
+                errs.push(Violation::new("custom", &self.name, &self.expr.source, actual, format!("{} could not be evaluated: {}", self.name, e)));
+            },
+        }
+        errs
+    }
+}
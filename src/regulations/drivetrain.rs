@@ -1,28 +1,80 @@
-use anyhow::Result;
 use serde::Deserialize;
 
 use crate::specs::Car;
-use super::CheckError;
+use super::{Violation, check_allowed, check_banned, check_banned_exact, check_max, check_min};
 
 #[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct Drivetrain {
     pub banned_types: Option<Vec<String>>,
+    pub allowed_types: Option<Vec<String>>,
+
+    pub allowed_gearboxes: Option<Vec<String>>,
+    /// Matched exactly, so banning Manual doesn't ban Automanual.
+    pub banned_gearboxes: Option<Vec<String>>,
+    pub min_gears: Option<usize>,
+    pub max_gears: Option<usize>,
+    pub allowed_differentials: Option<Vec<String>>,
+    pub banned_differentials: Option<Vec<String>>,
+
+    /// Share of the power sent to the front wheels, in percent.
+    pub min_power_to_front: Option<f32>,
+    pub max_power_to_front: Option<f32>,
+    pub min_final_drive: Option<f32>,
+    pub max_final_drive: Option<f32>,
+    /// The speed limiter has to be on and set to this speed (km/h) or below.
+    pub speed_limiter: Option<f32>,
 }
 
 impl Drivetrain {
-    pub fn check_car(&self, car: &Car) -> Result<()> {
+    pub fn check_car(&self, car: &Car) -> Vec<Violation> {
         let mut errs = Vec::new();
         if let Some(banned_types) = &self.banned_types {
             for bt in banned_types {
                 if car.drivetrain_type.to_lowercase().contains(&bt.trim().to_lowercase()) {
-                    errs.push(format!("illegal drive type {}", car.drivetrain_type));
+                    errs.push(Violation::banned("drivetrain", "banned_types", banned_types, &car.drivetrain_type, "drive type"));
                 }
             }
         }
-        if errs.len() > 0 {
-            return Err(CheckError::ErrDrivetrain(errs).into());
+        check_allowed(&mut errs, "drivetrain", "allowed_types", &self.allowed_types, &car.drivetrain_type, "drive type");
+
+        check_allowed(&mut errs, "drivetrain", "allowed_gearboxes", &self.allowed_gearboxes, &car.gearbox_type, "gearbox");
+        check_banned_exact(&mut errs, "drivetrain", "banned_gearboxes", &self.banned_gearboxes, &car.gearbox_type, "gearbox");
+        check_min(&mut errs, "drivetrain", "min_gears", self.min_gears, car.gear_count, "gear count");
+        check_max(&mut errs, "drivetrain", "max_gears", self.max_gears, car.gear_count, "gear count");
+        check_allowed(&mut errs, "drivetrain", "allowed_differentials", &self.allowed_differentials, &car.differential_type, "differential");
+        check_banned(&mut errs, "drivetrain", "banned_differentials", &self.banned_differentials, &car.differential_type, "differential");
+
+        let power_to_front = car.power_to_front.map(|p| (p * 1000.0).round() / 10.0);
+        check_min(&mut errs, "drivetrain", "min_power_to_front", self.min_power_to_front, power_to_front, "power to front (%)");
+        check_max(&mut errs, "drivetrain", "max_power_to_front", self.max_power_to_front, power_to_front, "power to front (%)");
+        check_min(&mut errs, "drivetrain", "min_final_drive", self.min_final_drive, car.final_drive, "final drive");
+        check_max(&mut errs, "drivetrain", "max_final_drive", self.max_final_drive, car.final_drive, "final drive");
+
+        if let Some(speed_limiter) = self.speed_limiter {
+            match car.speed_limiter {
+                Some(limit) if limit > 0.0 && limit <= speed_limiter => {},
+                Some(limit) if limit > 0.0 => errs.push(Violation::max("drivetrain", "speed_limiter", speed_limiter, limit, "speed limiter")),
+                Some(_) => errs.push(Violation::new("drivetrain", "speed_limiter", format!("<= {}", speed_limiter), "off", "speed limiter is off")),
+                None => errs.push(Violation::unmeasured("drivetrain", "speed_limiter", "speed limiter")),
+            }
         }
+        errs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn car(gearbox_type: &str) -> Car {
+        Car { gearbox_type: String::from(gearbox_type), ..Default::default() }
+    }
 
-        Ok(())
+    #[test]
+    fn manual_ban_allows_automanual() {
+        let drivetrain: Drivetrain = toml::from_str("banned_gearboxes = [\"Manual\"]").unwrap();
+        assert!(drivetrain.check_car(&car("Automanual")).is_empty());
+        assert_eq!(drivetrain.check_car(&car("Manual")).len(), 1);
     }
 }
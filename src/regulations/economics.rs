@@ -0,0 +1,46 @@
+use serde::Deserialize;
+
+use crate::specs::Car;
+use super::{Violation, check_max, check_min};
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Economics {
+    pub max_price: Option<f32>,
+    pub max_total_costs: Option<f32>,
+    pub max_material_cost: Option<f32>,
+    pub max_tooling_costs: Option<f32>,
+    pub max_engineering_time: Option<f32>,
+    pub max_engineering_costs: Option<f32>,
+    pub max_engine_total_cost: Option<f32>,
+    pub max_engine_material_cost: Option<f32>,
+    pub min_engine_production_units: Option<f32>,
+    pub max_engine_production_units: Option<f32>,
+
+    /// Trim price divided by trim total costs, e.g. 1.5 for a 50% markup.
+    pub min_margin: Option<f32>,
+    pub max_margin: Option<f32>,
+}
+
+impl Economics {
+    pub fn check_car(&self, car: &Car) -> Vec<Violation> {
+        let mut errs = Vec::new();
+        check_max(&mut errs, "economics", "max_price", self.max_price, car.trim_price, "trim price");
+        check_max(&mut errs, "economics", "max_total_costs", self.max_total_costs, car.trim_total_costs, "trim total costs");
+        check_max(&mut errs, "economics", "max_material_cost", self.max_material_cost, car.trim_material_cost, "trim material cost");
+        check_max(&mut errs, "economics", "max_tooling_costs", self.max_tooling_costs, car.trim_tooling_costs, "trim tooling costs");
+        check_max(&mut errs, "economics", "max_engineering_time", self.max_engineering_time, car.trim_engineering_time, "trim engineering time");
+        check_max(&mut errs, "economics", "max_engineering_costs", self.max_engineering_costs, car.trim_engineering_costs, "trim engineering costs");
+        check_max(&mut errs, "economics", "max_engine_total_cost", self.max_engine_total_cost, car.engine_total_cost, "engine total cost");
+        check_max(&mut errs, "economics", "max_engine_material_cost", self.max_engine_material_cost, car.engine_material_cost, "engine material cost");
+        check_min(&mut errs, "economics", "min_engine_production_units", self.min_engine_production_units, car.engine_production_units, "engine production units");
+        check_max(&mut errs, "economics", "max_engine_production_units", self.max_engine_production_units, car.engine_production_units, "engine production units");
+
+        let margin = car.trim_price.zip(car.trim_total_costs)
+            .filter(|(_, cost)| *cost > 0.0)
+            .map(|(price, cost)| (price / cost * 100.0).round() / 100.0);
+        check_min(&mut errs, "economics", "min_margin", self.min_margin, margin, "price to cost margin");
+        check_max(&mut errs, "economics", "max_margin", self.max_margin, margin, "price to cost margin");
+        errs
+    }
+}
@@ -1,134 +1,226 @@
-use anyhow::Result;
 use serde::Deserialize;
 
 use crate::specs::Car;
-use super::CheckError;
+use super::{Violation, check_allowed, check_banned};
 
 #[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct Engine {
     pub min_year: Option<usize>,
     pub max_year: Option<usize>,
+    pub min_cylinders: Option<usize>,
     pub max_cylinders: Option<usize>,
+    pub turbo_min_cylinders: Option<usize>,
     pub turbo_max_cylinders: Option<usize>,
-    pub octane: Option<f32>,
-    pub fuel_type: Option<Vec<String>>,
 
+    pub min_displacement: Option<usize>,
     pub max_displacement: Option<usize>,
+    pub min_stroke: Option<f32>,
     pub max_stroke: Option<f32>,
+    pub min_bore: Option<f32>,
     pub max_bore: Option<f32>,
+    pub min_intake_valves: Option<usize>,
     pub max_intake_valves: Option<usize>,
+    pub min_exhaust_valves: Option<usize>,
     pub max_exhaust_valves: Option<usize>,
+    pub min_total_valves: Option<usize>,
     pub max_total_valves: Option<usize>,
+    pub min_compression: Option<f32>,
     pub max_compression: Option<f32>,
+    pub min_rpm: Option<usize>,
     pub max_rpm: Option<usize>,
 
     pub catalytic_converter_banned: Option<Vec<String>>,
     pub intake_banned: Option<Vec<String>>,
     pub headers_banned: Option<Vec<String>>,
+
+    pub block_type_allowed: Option<Vec<String>>,
+    pub block_type_banned: Option<Vec<String>>,
+    pub block_material_allowed: Option<Vec<String>>,
+    pub block_material_banned: Option<Vec<String>>,
+    pub head_material_allowed: Option<Vec<String>>,
+    pub head_material_banned: Option<Vec<String>>,
+    pub head_type_allowed: Option<Vec<String>>,
+    pub head_type_banned: Option<Vec<String>>,
+    pub vvt_allowed: Option<Vec<String>>,
+    pub vvt_banned: Option<Vec<String>>,
+    pub vvl_allowed: Option<Vec<String>>,
+    pub vvl_banned: Option<Vec<String>>,
+    pub crankshaft_allowed: Option<Vec<String>>,
+    pub crankshaft_banned: Option<Vec<String>>,
+    pub conrods_allowed: Option<Vec<String>>,
+    pub conrods_banned: Option<Vec<String>>,
+    pub pistons_allowed: Option<Vec<String>>,
+    pub pistons_banned: Option<Vec<String>>,
+    pub balance_shaft_allowed: Option<Vec<String>>,
+    pub balance_shaft_banned: Option<Vec<String>>,
+    pub throttle_configuration_allowed: Option<Vec<String>>,
+    pub throttle_configuration_banned: Option<Vec<String>>,
 }
 
 impl Engine {
-    pub fn check_car(&self, car: &Car) -> Result<()> {
+    pub fn check_car(&self, car: &Car) -> Vec<Violation> {
         let mut errs = Vec::new();
         if let Some(min_year) = self.min_year {
             if car.engine_year < min_year {
-                errs.push(format!("engine year {}", car.engine_year));
+                errs.push(Violation::min("engine", "min_year", min_year, car.engine_year, "engine year"));
             }
         }
         if let Some(max_year) = self.max_year {
             if car.engine_year > max_year {
-                errs.push(format!("engine year {}", car.engine_year));
+                errs.push(Violation::max("engine", "max_year", max_year, car.engine_year, "engine year"));
+            }
+        }
+        if let Some(min_cylinders) = self.min_cylinders {
+            if car.cylinder_count < min_cylinders {
+                errs.push(Violation::min("engine", "min_cylinders", min_cylinders, car.cylinder_count, "cylinder count"));
             }
         }
         if let Some(max_cylinders) = self.max_cylinders {
             if car.cylinder_count > max_cylinders {
-                errs.push(format!("cylinder count {}", car.cylinder_count));
+                errs.push(Violation::max("engine", "max_cylinders", max_cylinders, car.cylinder_count, "cylinder count"));
             }
         }
-        if let Some(turbo_max_cylinders) = self.turbo_max_cylinders {
-            if car.has_turbo() && car.cylinder_count > turbo_max_cylinders {
-                errs.push(String::from("turbo_max_cylinders"));
+        if let Some(turbo_min_cylinders) = self.turbo_min_cylinders {
+            if car.has_turbo() && car.cylinder_count < turbo_min_cylinders {
+                errs.push(Violation::min("engine", "turbo_min_cylinders", turbo_min_cylinders, car.cylinder_count, "turbo cylinder count"));
             }
         }
-        if let Some(octane) = self.octane {
-            if car.octane > octane {
-                errs.push(format!("fuel octane {}", car.octane));
+        if let Some(turbo_max_cylinders) = self.turbo_max_cylinders {
+            if car.has_turbo() && car.cylinder_count > turbo_max_cylinders {
+                errs.push(Violation::max("engine", "turbo_max_cylinders", turbo_max_cylinders, car.cylinder_count, "turbo cylinder count"));
             }
         }
-        if let Some(allowed_fuels) = &self.fuel_type {
-            for f in allowed_fuels {
-                if f.trim() == car.fuel_type {
-                    errs.push(format!("illegal fuel type {}", car.fuel_type));
-                }
+
+        if let Some(min_displacement) = self.min_displacement {
+            if car.displacement < min_displacement {
+                errs.push(Violation::min("engine", "min_displacement", min_displacement, car.displacement, "displacement"));
             }
         }
-
         if let Some(max_displacement) = self.max_displacement {
             if car.displacement > max_displacement {
-                errs.push(format!("displacement {}", car.displacement));
+                errs.push(Violation::max("engine", "max_displacement", max_displacement, car.displacement, "displacement"));
+            }
+        }
+        if let Some(min_stroke) = self.min_stroke {
+            if car.stroke < min_stroke {
+                errs.push(Violation::min("engine", "min_stroke", min_stroke, car.stroke, "stroke"));
             }
         }
         if let Some(max_stroke) = self.max_stroke {
             if car.stroke > max_stroke {
-                errs.push(format!("stroke {}", car.stroke));
+                errs.push(Violation::max("engine", "max_stroke", max_stroke, car.stroke, "stroke"));
+            }
+        }
+        if let Some(min_bore) = self.min_bore {
+            if car.bore < min_bore {
+                errs.push(Violation::min("engine", "min_bore", min_bore, car.bore, "bore"));
             }
         }
         if let Some(max_bore) = self.max_bore {
             if car.bore > max_bore {
-                errs.push(format!("bore {}", car.bore));
+                errs.push(Violation::max("engine", "max_bore", max_bore, car.bore, "bore"));
+            }
+        }
+        if let Some(min_intake_valves) = self.min_intake_valves {
+            if car.intake_valves < min_intake_valves {
+                errs.push(Violation::min("engine", "min_intake_valves", min_intake_valves, car.intake_valves, "intake valves"));
             }
         }
         if let Some(max_intake_valves) = self.max_intake_valves {
             if car.intake_valves > max_intake_valves {
-                errs.push(format!("intake valves {}", car.intake_valves));
+                errs.push(Violation::max("engine", "max_intake_valves", max_intake_valves, car.intake_valves, "intake valves"));
+            }
+        }
+        if let Some(min_exhaust_valves) = self.min_exhaust_valves {
+            if car.exhaust_valves < min_exhaust_valves {
+                errs.push(Violation::min("engine", "min_exhaust_valves", min_exhaust_valves, car.exhaust_valves, "exhaust valves"));
             }
         }
         if let Some(max_exhaust_valves) = self.max_exhaust_valves {
             if car.exhaust_valves > max_exhaust_valves {
-                errs.push(format!("exhaust valves {}", car.exhaust_valves));
+                errs.push(Violation::max("engine", "max_exhaust_valves", max_exhaust_valves, car.exhaust_valves, "exhaust valves"));
+            }
+        }
+        if let Some(min_total_valves) = self.min_total_valves {
+            let total_valves = car.intake_valves + car.exhaust_valves;
+            if total_valves < min_total_valves {
+                errs.push(Violation::min("engine", "min_total_valves", min_total_valves, total_valves, "total valves"));
             }
         }
         if let Some(max_total_valves) = self.max_total_valves {
             let total_valves = car.intake_valves + car.exhaust_valves;
             if total_valves > max_total_valves {
-                errs.push(format!("total valves {}", total_valves));
+                errs.push(Violation::max("engine", "max_total_valves", max_total_valves, total_valves, "total valves"));
+            }
+        }
+        if let Some(min_compression) = self.min_compression {
+            if car.compression < min_compression {
+                errs.push(Violation::min("engine", "min_compression", min_compression, car.compression, "compression"));
             }
         }
         if let Some(max_compression) = self.max_compression {
             if car.compression > max_compression {
-                errs.push(format!("compression {}", car.compression));
+                errs.push(Violation::max("engine", "max_compression", max_compression, car.compression, "compression"));
+            }
+        }
+        if let Some(min_rpm) = self.min_rpm {
+            if car.rpm < min_rpm {
+                errs.push(Violation::min("engine", "min_rpm", min_rpm, car.rpm, "max rpm"));
             }
         }
         if let Some(max_rpm) = self.max_rpm {
             if car.rpm > max_rpm {
-                errs.push(format!("max rpm {}", car.rpm));
+                errs.push(Violation::max("engine", "max_rpm", max_rpm, car.rpm, "max rpm"));
             }
         }
 
         if let Some(catalytic_converter_banned) = &self.catalytic_converter_banned {
             for cat in catalytic_converter_banned {
                 if car.catalytic_converter.to_lowercase().contains(&cat.trim().to_lowercase()) {
-                    errs.push(format!("illegal cat conv {}", car.catalytic_converter))
+                    errs.push(Violation::banned("engine", "catalytic_converter_banned", catalytic_converter_banned, &car.catalytic_converter, "cat conv"));
                 }
             }
         }
         if let Some(intake_banned) = &self.intake_banned {
             for intake in intake_banned {
                 if car.intake_type.to_lowercase().contains(&intake.trim().to_lowercase()) {
-                    errs.push(format!("illegal intake {}", car.intake_type));
+                    errs.push(Violation::banned("engine", "intake_banned", intake_banned, &car.intake_type, "intake"));
                 }
             }
         }
         if let Some(headers_banned) = &self.headers_banned {
             for header in headers_banned {
                 if car.headers.trim() == header.trim() {
-                    errs.push(format!("illegal headers {}", car.headers));
+                    errs.push(Violation::banned("engine", "headers_banned", headers_banned, &car.headers, "headers"));
                 }
             }
         }
 
-        if errs.len() > 0 { return Err(CheckError::ErrEngine(errs).into()); }
+        check_allowed(&mut errs, "engine", "block_type_allowed", &self.block_type_allowed, &car.block_type, "block type");
+        check_banned(&mut errs, "engine", "block_type_banned", &self.block_type_banned, &car.block_type, "block type");
+        check_allowed(&mut errs, "engine", "block_material_allowed", &self.block_material_allowed, &car.block_material, "block material");
+        check_banned(&mut errs, "engine", "block_material_banned", &self.block_material_banned, &car.block_material, "block material");
+        check_allowed(&mut errs, "engine", "head_material_allowed", &self.head_material_allowed, &car.head_material, "head material");
+        check_banned(&mut errs, "engine", "head_material_banned", &self.head_material_banned, &car.head_material, "head material");
+        check_allowed(&mut errs, "engine", "head_type_allowed", &self.head_type_allowed, &car.head_type, "head type");
+        check_banned(&mut errs, "engine", "head_type_banned", &self.head_type_banned, &car.head_type, "head type");
+        check_allowed(&mut errs, "engine", "vvt_allowed", &self.vvt_allowed, &car.vvt, "VVT");
+        check_banned(&mut errs, "engine", "vvt_banned", &self.vvt_banned, &car.vvt, "VVT");
+        check_allowed(&mut errs, "engine", "vvl_allowed", &self.vvl_allowed, &car.vvl, "VVL");
+        check_banned(&mut errs, "engine", "vvl_banned", &self.vvl_banned, &car.vvl, "VVL");
+        check_allowed(&mut errs, "engine", "crankshaft_allowed", &self.crankshaft_allowed, &car.crankshaft, "crankshaft");
+        check_banned(&mut errs, "engine", "crankshaft_banned", &self.crankshaft_banned, &car.crankshaft, "crankshaft");
+        check_allowed(&mut errs, "engine", "conrods_allowed", &self.conrods_allowed, &car.conrods, "conrods");
+        check_banned(&mut errs, "engine", "conrods_banned", &self.conrods_banned, &car.conrods, "conrods");
+        check_allowed(&mut errs, "engine", "pistons_allowed", &self.pistons_allowed, &car.pistons, "pistons");
+        check_banned(&mut errs, "engine", "pistons_banned", &self.pistons_banned, &car.pistons, "pistons");
+        check_allowed(&mut errs, "engine", "balance_shaft_allowed", &self.balance_shaft_allowed, &car.balance_shaft, "balance shaft");
+        check_banned(&mut errs, "engine", "balance_shaft_banned", &self.balance_shaft_banned, &car.balance_shaft, "balance shaft");
+        check_allowed(&mut errs, "engine", "throttle_configuration_allowed", &self.throttle_configuration_allowed, &car.throttle_configuration, "throttle configuration");
+        check_banned(&mut errs, "engine", "throttle_configuration_banned", &self.throttle_configuration_banned, &car.throttle_configuration, "throttle configuration");
 
-        Ok(())
+        errs
     }
 }
+
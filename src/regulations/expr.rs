@@ -0,0 +1,341 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use anyhow::{Result, anyhow, bail};
+use serde::Deserialize;
+
+/// A value an expression works with. Car fields and raw columns that parse as
+/// numbers are numbers, everything else is text.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Number(f64),
+    Text(String),
+    Bool(bool),
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{}", n),
+            Self::Text(s) => write!(f, "{:?}", s),
+            Self::Bool(b) => write!(f, "{}", b),
+        }
+    }
+}
+
+impl Value {
+    fn number(&self) -> Result<f64> {
+        match self {
+            Self::Number(n) => Ok(*n),
+            other => bail!("expected a number, found {}", other),
+        }
+    }
+
+    fn bool(&self) -> Result<bool> {
+        match self {
+            Self::Bool(b) => Ok(*b),
+            other => bail!("expected true or false, found {}", other),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Add, Sub, Mul, Div,
+    Lt, Le, Gt, Ge, Eq, Ne, Contains,
+    And, Or,
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Literal(Value),
+    Field(String),
+    Neg(Box<Node>),
+    Not(Box<Node>),
+    Binary(Op, Box<Node>, Box<Node>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Text(String),
+    Ident(String),
+    Op(&'static str),
+    Open,
+    Close,
+}
+
+fn tokenize(src: &str) -> Result<Vec<Token>> {
+    let chars: Vec<char> = src.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c.is_ascii_digit() || c == '.' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') { i += 1; }
+            let s: String = chars[start..i].iter().collect();
+            tokens.push(Token::Number(s.parse().map_err(|_| anyhow!("bad number {}", s))?));
+        } else if c == '"' {
+            let start = i + 1;
+            i += 1;
+            while i < chars.len() && chars[i] != '"' { i += 1; }
+            if i == chars.len() { bail!("unterminated string"); }
+            tokens.push(Token::Text(chars[start..i].iter().collect()));
+            i += 1;
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') { i += 1; }
+            tokens.push(Token::Ident(chars[start..i].iter().collect()));
+        } else if c == '(' {
+            tokens.push(Token::Open);
+            i += 1;
+        } else if c == ')' {
+            tokens.push(Token::Close);
+            i += 1;
+        } else {
+            let two: String = chars[i..(i + 2).min(chars.len())].iter().collect();
+            let op = ["<=", ">=", "==", "!=", "&&", "||"].into_iter().find(|op| *op == two)
+                .or_else(|| ["+", "-", "*", "/", "<", ">", "!"].into_iter().find(|op| op.starts_with(c)))
+                .ok_or_else(|| anyhow!("unexpected character '{}'", c))?;
+            tokens.push(Token::Op(op));
+            i += op.len();
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    /// Consumes the next token if it is one of `ops`, given as symbols or keywords.
+    fn eat(&mut self, ops: &[(&str, Op)]) -> Option<Op> {
+        let op = match self.peek()? {
+            Token::Op(s) => ops.iter().find(|(o, _)| o == s)?.1,
+            Token::Ident(s) => ops.iter().find(|(o, _)| o == s)?.1,
+            _ => return None,
+        };
+        self.pos += 1;
+        Some(op)
+    }
+
+    /// Consumes the next token if it is one of `words`.
+    fn eat_word(&mut self, words: &[&str]) -> bool {
+        let found = match self.peek() {
+            Some(Token::Op(s)) => words.contains(s),
+            Some(Token::Ident(s)) => words.contains(&s.as_str()),
+            _ => false,
+        };
+        if found { self.pos += 1; }
+        found
+    }
+
+    fn binary(&mut self, ops: &[(&str, Op)], next: fn(&mut Self) -> Result<Node>) -> Result<Node> {
+        let mut node = next(self)?;
+        while let Some(op) = self.eat(ops) {
+            node = Node::Binary(op, Box::new(node), Box::new(next(self)?));
+        }
+        Ok(node)
+    }
+
+    fn or(&mut self) -> Result<Node> {
+        self.binary(&[("or", Op::Or), ("||", Op::Or)], Self::and)
+    }
+
+    fn and(&mut self) -> Result<Node> {
+        self.binary(&[("and", Op::And), ("&&", Op::And)], Self::not)
+    }
+
+    fn not(&mut self) -> Result<Node> {
+        if self.eat_word(&["not", "!"]) {
+            return Ok(Node::Not(Box::new(self.not()?)));
+        }
+        self.comparison()
+    }
+
+    fn comparison(&mut self) -> Result<Node> {
+        let ops = [("<=", Op::Le), (">=", Op::Ge), ("<", Op::Lt), (">", Op::Gt), ("==", Op::Eq), ("!=", Op::Ne), ("contains", Op::Contains)];
+        let left = self.sum()?;
+        match self.eat(&ops) {
+            Some(op) => Ok(Node::Binary(op, Box::new(left), Box::new(self.sum()?))),
+            None => Ok(left),
+        }
+    }
+
+    fn sum(&mut self) -> Result<Node> {
+        self.binary(&[("+", Op::Add), ("-", Op::Sub)], Self::product)
+    }
+
+    fn product(&mut self) -> Result<Node> {
+        self.binary(&[("*", Op::Mul), ("/", Op::Div)], Self::unary)
+    }
+
+    fn unary(&mut self) -> Result<Node> {
+        if self.eat_word(&["-"]) {
+            return Ok(Node::Neg(Box::new(self.unary()?)));
+        }
+        self.atom()
+    }
+
+    fn atom(&mut self) -> Result<Node> {
+        let token = self.tokens.get(self.pos).cloned().ok_or_else(|| anyhow!("unexpected end of expression"))?;
+        self.pos += 1;
+        Ok(match token {
+            Token::Number(n) => Node::Literal(Value::Number(n)),
+            Token::Text(s) => Node::Literal(Value::Text(s)),
+            Token::Ident(s) if s == "true" => Node::Literal(Value::Bool(true)),
+            Token::Ident(s) if s == "false" => Node::Literal(Value::Bool(false)),
+            Token::Ident(s) if ["and", "or", "not", "contains"].contains(&s.as_str()) => bail!("unexpected `{}`", s),
+            Token::Ident(s) => Node::Field(s),
+            Token::Open => {
+                let node = self.or()?;
+                if self.peek() != Some(&Token::Close) { bail!("missing `)`"); }
+                self.pos += 1;
+                node
+            },
+            Token::Close => bail!("unexpected `)`"),
+            Token::Op(op) => bail!("unexpected `{}`", op),
+        })
+    }
+}
+
+/// A parsed expression from a `[[custom]]` rule, e.g.
+/// `displacement / cylinder_count <= 500 and not intake_type contains "Race"`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Expression {
+    pub source: String,
+    root: Node,
+}
+
+impl TryFrom<String> for Expression {
+    type Error = anyhow::Error;
+
+    fn try_from(source: String) -> Result<Self> {
+        let mut parser = Parser { tokens: tokenize(&source)?, pos: 0 };
+        let root = parser.or().map_err(|e| anyhow!("{} in `{}`", e, source))?;
+        if parser.pos != parser.tokens.len() {
+            bail!("unexpected {:?} in `{}`", parser.tokens[parser.pos], source);
+        }
+        Ok(Self { source, root })
+    }
+}
+
+impl Expression {
+    /// Evaluates the expression, looking fields up in `fields`.
+    pub fn eval(&self, fields: &HashMap<String, Value>) -> Result<Value> {
+        eval(&self.root, fields)
+    }
+
+    /// Every field the expression reads.
+    pub fn fields(&self) -> Vec<&str> {
+        fn walk<'a>(node: &'a Node, out: &mut Vec<&'a str>) {
+            match node {
+                Node::Literal(_) => {},
+                Node::Field(name) => if !out.contains(&name.as_str()) { out.push(name) },
+                Node::Neg(n) | Node::Not(n) => walk(n, out),
+                Node::Binary(_, l, r) => { walk(l, out); walk(r, out); },
+            }
+        }
+        let mut out = Vec::new();
+        walk(&self.root, &mut out);
+        out
+    }
+}
+
+fn eval(node: &Node, fields: &HashMap<String, Value>) -> Result<Value> {
+    Ok(match node {
+        Node::Literal(v) => v.clone(),
+        Node::Field(name) => fields.get(name).cloned().ok_or_else(|| anyhow!("unknown field `{}`", name))?,
+        Node::Neg(n) => Value::Number(-eval(n, fields)?.number()?),
+        Node::Not(n) => Value::Bool(!eval(n, fields)?.bool()?),
+        Node::Binary(Op::And, l, r) => Value::Bool(eval(l, fields)?.bool()? && eval(r, fields)?.bool()?),
+        Node::Binary(Op::Or, l, r) => Value::Bool(eval(l, fields)?.bool()? || eval(r, fields)?.bool()?),
+        Node::Binary(op, l, r) => {
+            let (l, r) = (eval(l, fields)?, eval(r, fields)?);
+            match (op, &l, &r) {
+                (Op::Eq, Value::Text(a), Value::Text(b)) => Value::Bool(a.trim().eq_ignore_ascii_case(b.trim())),
+                (Op::Ne, Value::Text(a), Value::Text(b)) => Value::Bool(!a.trim().eq_ignore_ascii_case(b.trim())),
+                (Op::Contains, Value::Text(a), Value::Text(b)) => Value::Bool(a.to_lowercase().contains(&b.trim().to_lowercase())),
+                (Op::Contains, _, _) => bail!("contains needs text on both sides, found {} and {}", l, r),
+                (Op::Eq, Value::Bool(a), Value::Bool(b)) => Value::Bool(a == b),
+                (Op::Ne, Value::Bool(a), Value::Bool(b)) => Value::Bool(a != b),
+                _ => {
+                    let (a, b) = (l.number()?, r.number()?);
+                    match op {
+                        Op::Add => Value::Number(a + b),
+                        Op::Sub => Value::Number(a - b),
+                        Op::Mul => Value::Number(a * b),
+                        Op::Div if b == 0.0 => bail!("division by zero"),
+                        Op::Div => Value::Number(a / b),
+                        Op::Lt => Value::Bool(a < b),
+                        Op::Le => Value::Bool(a <= b),
+                        Op::Gt => Value::Bool(a > b),
+                        Op::Ge => Value::Bool(a >= b),
+                        Op::Eq => Value::Bool(a == b),
+                        Op::Ne => Value::Bool(a != b),
+                        Op::And | Op::Or | Op::Contains => unreachable!(),
+                    }
+                },
+            }
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval_str(src: &str, fields: &[(&str, Value)]) -> Result<Value> {
+        let fields = fields.iter().map(|(k, v)| (k.to_string(), v.clone())).collect();
+        Expression::try_from(src.to_string())?.eval(&fields)
+    }
+
+    #[test]
+    fn precedence() {
+        assert_eq!(eval_str("1 + 2 * 3", &[]).unwrap(), Value::Number(7.0));
+        assert_eq!(eval_str("(1 + 2) * 3", &[]).unwrap(), Value::Number(9.0));
+        assert_eq!(eval_str("10 - 4 - 3", &[]).unwrap(), Value::Number(3.0));
+        assert_eq!(eval_str("-2 * 3 + 1 < 0", &[]).unwrap(), Value::Bool(true));
+        assert_eq!(eval_str("true or false and false", &[]).unwrap(), Value::Bool(true));
+        assert_eq!(eval_str("not 1 > 2 and 2 > 1", &[]).unwrap(), Value::Bool(true));
+    }
+
+    #[test]
+    fn fields() {
+        let fields = [("displacement", Value::Number(1600.0)), ("cylinder_count", Value::Number(4.0))];
+        assert_eq!(eval_str("displacement / cylinder_count <= 400", &fields).unwrap(), Value::Bool(true));
+        let expr = Expression::try_from(String::from("displacement / cylinder_count > displacement")).unwrap();
+        assert_eq!(expr.fields(), vec!["displacement", "cylinder_count"]);
+    }
+
+    #[test]
+    fn contains() {
+        let fields = [("intake_type", Value::Text(String::from("Race Intake")))];
+        assert_eq!(eval_str("intake_type contains \"race\"", &fields).unwrap(), Value::Bool(true));
+        assert_eq!(eval_str("not intake_type contains \"Sport\"", &fields).unwrap(), Value::Bool(true));
+        assert_eq!(eval_str("intake_type == \" race intake \"", &fields).unwrap(), Value::Bool(true));
+        assert!(eval_str("intake_type contains 1", &fields).is_err());
+    }
+
+    #[test]
+    fn chained_comparison_is_rejected() {
+        assert!(Expression::try_from(String::from("1 < 2 < 3")).is_err());
+        assert!(Expression::try_from(String::from("1 < 2 and 2 < 3")).is_ok());
+    }
+
+    #[test]
+    fn division_by_zero() {
+        assert!(eval_str("1 / 0 > 0", &[]).is_err());
+        assert!(eval_str("1 / (2 - 2)", &[]).is_err());
+    }
+}
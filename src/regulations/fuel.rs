@@ -0,0 +1,61 @@
+use serde::Deserialize;
+
+use crate::specs::Car;
+use super::{Violation, check_allowed, check_banned_exact, check_max, check_min};
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Fuel {
+    /// Fuel types the engine may run on, e.g. `["Unleaded"]`.
+    pub allowed_types: Option<Vec<String>>,
+    /// Fuel types the engine may not run on. Matched exactly, so banning
+    /// Leaded doesn't ban Unleaded.
+    pub banned_types: Option<Vec<String>>,
+    pub min_octane: Option<f32>,
+    pub max_octane: Option<f32>,
+    pub max_octane_offset: Option<f32>,
+    pub min_afr: Option<f32>,
+    pub max_afr: Option<f32>,
+    pub min_ron_rating: Option<f32>,
+    pub max_ron_rating: Option<f32>,
+}
+
+impl Fuel {
+    pub fn check_car(&self, car: &Car) -> Vec<Violation> {
+        let mut errs = Vec::new();
+        check_allowed(&mut errs, "fuel", "allowed_types", &self.allowed_types, &car.fuel_type, "fuel type");
+        check_banned_exact(&mut errs, "fuel", "banned_types", &self.banned_types, &car.fuel_type, "fuel type");
+        if let Some(min_octane) = self.min_octane {
+            if car.octane < min_octane {
+                errs.push(Violation::min("fuel", "min_octane", min_octane, car.octane, "fuel octane"));
+            }
+        }
+        if let Some(max_octane) = self.max_octane {
+            if car.octane > max_octane {
+                errs.push(Violation::max("fuel", "max_octane", max_octane, car.octane, "fuel octane"));
+            }
+        }
+        check_max(&mut errs, "fuel", "max_octane_offset", self.max_octane_offset, car.octane_offset, "octane offset");
+        check_min(&mut errs, "fuel", "min_afr", self.min_afr, car.afr, "AFR");
+        check_max(&mut errs, "fuel", "max_afr", self.max_afr, car.afr, "AFR");
+        check_min(&mut errs, "fuel", "min_ron_rating", self.min_ron_rating, car.engine_ron_rating, "engine RON rating");
+        check_max(&mut errs, "fuel", "max_ron_rating", self.max_ron_rating, car.engine_ron_rating, "engine RON rating");
+        errs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn car(fuel_type: &str) -> Car {
+        Car { fuel_type: String::from(fuel_type), ..Default::default() }
+    }
+
+    #[test]
+    fn leaded_ban_allows_unleaded() {
+        let fuel: Fuel = toml::from_str("banned_types = [\"Leaded\"]").unwrap();
+        assert!(fuel.check_car(&car("Unleaded")).is_empty());
+        assert_eq!(fuel.check_car(&car("leaded")).len(), 1);
+    }
+}
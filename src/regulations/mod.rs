@@ -1,93 +1,217 @@
-use anyhow::Result;
+use std::path::Path;
+use std::str::FromStr;
+use anyhow::{Result, bail};
 
 use serde::Deserialize;
 
+use crate::fields::struct_fields;
 use crate::specs::Car;
 
 mod stats;
 mod engine;
+mod fuel;
+mod aspiration;
+mod power;
 mod wheels;
+mod brakes;
+mod suspension;
+mod aero;
+mod performance;
+mod weight;
+mod economics;
 mod chassis;
 mod drivetrain;
 mod others;
+mod custom;
+pub mod expr;
+mod report;
+mod rules;
 
-use stats::*;
-use engine::*;
-use wheels::*;
-use chassis::*;
-use drivetrain::*;
-use others::*;
-
-#[derive(Debug)]
-pub enum CheckError {
-    Regulations(Vec<anyhow::Error>),
-    ErrStats(Vec<String>),
-    ErrEngine(Vec<String>),
-    ErrWheels(Vec<String>),
-    ErrChassis(Vec<String>),
-    ErrDrivetrain(Vec<String>),
-    ErrOthers(Vec<String>),
-}
-
-impl std::fmt::Display for CheckError {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::result::Result<(), std::fmt::Error> {
-        write!(f, "{}", format!("{:?}", self))
-    }
-}
-
-impl std::error::Error for CheckError {}
+pub use stats::*;
+pub use engine::*;
+pub use fuel::*;
+pub use aspiration::*;
+pub use power::*;
+pub use wheels::*;
+pub use brakes::*;
+pub use suspension::*;
+pub use aero::*;
+pub use performance::*;
+pub use weight::*;
+pub use economics::*;
+pub use chassis::*;
+pub use drivetrain::*;
+pub use others::*;
+pub use custom::*;
+pub use report::*;
+pub use rules::*;
 
 #[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct Regulations {
     pub stats: Option<Stats>,
     pub rules: Option<Rules>,
     pub engine: Option<Engine>,
+    pub fuel: Option<Fuel>,
+    pub aspiration: Option<Aspiration>,
+    pub power: Option<Power>,
     pub wheels: Option<Wheels>,
+    pub brakes: Option<Brakes>,
+    pub suspension: Option<Suspension>,
+    pub aero: Option<Aero>,
+    pub performance: Option<Performance>,
+    pub weight: Option<Weight>,
+    pub economics: Option<Economics>,
+    #[serde(alias = "body")]
     pub chassis: Option<Chassis>,
     pub drivetrain: Option<Drivetrain>,
     pub other: Option<Others>,
+    pub custom: Option<Vec<Custom>>,
+}
+
+/// Keys that were renamed or moved to another section, so older
+/// regulations get told what to use instead.
+const MOVED_KEYS: &[(&str, &str, &str)] = &[
+    ("engine", "fuel_type", "fuel.allowed_types"),
+    ("engine", "min_octane", "fuel.min_octane"),
+    ("engine", "octane", "fuel.max_octane"),
+    ("aero", "cooling.required_per_amount", "aero.cooling.approx_required_per_amount"),
+    ("weight", "power_unit", "power.power_unit"),
+    ("performance", "min_braking_distance", "brakes.min_braking_distance"),
+    ("performance", "max_braking_distance", "brakes.max_braking_distance"),
+];
+
+impl FromStr for Regulations {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let table: toml::Value = toml::from_str(s)?;
+        for (section, key, moved_to) in MOVED_KEYS {
+            if is_set(&table, section, key) {
+                bail!("`{}.{}` is no longer supported, use `{}` instead", section, key, moved_to);
+            }
+        }
+        let regulations: Self = toml::from_str(s)?;
+        if let Some(aspiration) = &regulations.aspiration {
+            aspiration.validate()?;
+        }
+        if let Some(custom) = &regulations.custom {
+            validate_custom(custom)?;
+        }
+        if let Some(rules) = &regulations.rules {
+            rules.validate(
+                |section, key| regulations.has_check(section, key),
+                |section, key| section == "custom" || is_set(&table, section, key),
+            )?;
+        }
+        Ok(regulations)
+    }
+}
+
+/// Whether `key` is given a value in `section` of the raw toml, i.e. whether that
+/// check runs at all. `key` may name a sub-table key like `cooling.min_amount`.
+fn is_set(table: &toml::Value, section: &str, key: &str) -> bool {
+    let sections: &[&str] = match section {
+        "chassis" | "body" => &["chassis", "body"],
+        _ => &[section],
+    };
+    sections.iter()
+        .filter_map(|s| table.get(s))
+        .any(|t| key.split('.').try_fold(t, |t, k| t.get(k)).is_some())
 }
 
 impl Regulations {
-    pub fn load() -> Result<Self> {
-        Ok(toml::from_str(&std::fs::read_to_string("regulations.toml")?)?)
+    pub fn load(path: &Path) -> Result<Self> {
+        std::fs::read_to_string(path)?.parse()
     }
 
-    pub fn check_car(&self, car: &Car) -> Result<()> {
-        let mut errs = Vec::new();
+    /// Whether `key` is a check in `section`, i.e. a key of that table.
+    fn has_check(&self, section: &str, key: &str) -> bool {
+        let fields = match section {
+            "custom" => return self.custom.iter().flatten().any(|c| c.name == key),
+            "stats" => struct_fields::<Stats>(),
+            "engine" => struct_fields::<Engine>(),
+            "fuel" => struct_fields::<Fuel>(),
+            "aspiration" => struct_fields::<Aspiration>(),
+            "power" => struct_fields::<Power>(),
+            "wheels" => struct_fields::<Wheels>(),
+            "brakes" => struct_fields::<Brakes>(),
+            "suspension" => struct_fields::<Suspension>(),
+            "aero" => match key.strip_prefix("cooling.") {
+                Some(key) => return struct_fields::<Cooling>().contains(&key),
+                None => struct_fields::<Aero>(),
+            },
+            "performance" => struct_fields::<Performance>(),
+            "chassis" | "body" => struct_fields::<Chassis>(),
+            "drivetrain" => struct_fields::<Drivetrain>(),
+            "other" => struct_fields::<Others>(),
+            "weight" => struct_fields::<Weight>(),
+            "economics" => struct_fields::<Economics>(),
+            _ => return false,
+        };
+        fields.contains(&key)
+    }
+
+    pub fn check_car(&self, car: &Car) -> CheckReport {
+        let mut report = CheckReport::default();
+        for warning in &car.import_warnings {
+            report.violations.push(Violation::new("import", "csv", "", "", warning.clone()).warning());
+        }
         if let Some(stats) = &self.stats {
-            if let Err(e) = stats.check_car(car, &self.rules) {
-                errs.push(e);
-            }
+            report.extend(stats.check_car(car));
         }
         if let Some(engine) = &self.engine {
-            if let Err(e) = engine.check_car(car) {
-                errs.push(e);
-            }
+            report.extend(engine.check_car(car));
+        }
+        if let Some(fuel) = &self.fuel {
+            report.extend(fuel.check_car(car));
+        }
+        if let Some(aspiration) = &self.aspiration {
+            report.extend(aspiration.check_car(car));
+        }
+        if let Some(power) = &self.power {
+            report.extend(power.check_car(car));
         }
         if let Some(wheels) = &self.wheels {
-            if let Err(e) = wheels.check_car(car) {
-                errs.push(e);
-            }
+            report.extend(wheels.check_car(car));
+        }
+        if let Some(brakes) = &self.brakes {
+            report.extend(brakes.check_car(car));
+        }
+        if let Some(suspension) = &self.suspension {
+            report.extend(suspension.check_car(car));
+        }
+        if let Some(aero) = &self.aero {
+            report.extend(aero.check_car(car));
+        }
+        if let Some(performance) = &self.performance {
+            report.extend(performance.check_car(car));
+        }
+        if let Some(weight) = &self.weight {
+            let power_unit = self.power.as_ref().and_then(|p| p.power_unit).unwrap_or_default();
+            report.extend(weight.check_car(car, power_unit));
+        }
+        if let Some(economics) = &self.economics {
+            report.extend(economics.check_car(car));
         }
         if let Some(chassis) = &self.chassis {
-            if let Err(e) = chassis.check_car(car) {
-                errs.push(e);
-            }
+            report.extend(chassis.check_car(car));
         }
         if let Some(drivetrain) = &self.drivetrain {
-            if let Err(e) = drivetrain.check_car(car) {
-                errs.push(e);
-            }
+            report.extend(drivetrain.check_car(car));
         }
         if let Some(others) = &self.other {
-            if let Err(e) = others.check_car(car) {
-                errs.push(e);
+            report.extend(others.check_car(car));
+        }
+        if let Some(custom) = &self.custom {
+            let fields = custom_fields(car);
+            for rule in custom {
+                report.extend(rule.check_car(&fields));
             }
         }
-        if errs.len() > 0 {
-            return Err(CheckError::Regulations(errs).into());
+        if let Some(rules) = &self.rules {
+            rules.apply(&mut report);
         }
-        Ok(())
+        report
     }
 }
@@ -1,8 +1,7 @@
-use anyhow::Result;
 use serde::Deserialize;
 
 use crate::specs::Car;
-use super::CheckError;
+use super::Violation;
 
 #[derive(Debug, Deserialize, Clone)]
 #[serde(untagged)]
@@ -26,6 +25,7 @@ impl ReqPart {
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct Others {
     pub required_parts: Option<Vec<ReqPart>>,
     pub banned_parts: Option<Vec<String>>,
@@ -34,29 +34,27 @@ pub struct Others {
 }
 
 impl Others {
-    pub fn check_car(&self, car: &Car) -> Result<()> {
+    pub fn check_car(&self, car: &Car) -> Vec<Violation> {
         let mut errs = Vec::new();
         let min_quality = self.min_quality.unwrap_or(-15);
         let max_quality = self.max_quality.unwrap_or(15);
-        for (k, v) in &car.raw {
-            if k.trim().ends_with("_quality") {
-                // worst code ive ever written
-                if let Ok(v) = v.trim().parse::<f32>() {
-                    let v = v as isize;
-                    if !(v >= min_quality && v <= max_quality) {
-                        errs.push(format!("illegal quality {}", k));
-                    }
-                } else {
-                    errs.push(format!("incorrect int {}", k));
+        for (k, v) in car.qualities() {
+            if let Some(v) = v {
+                let v = v as isize;
+                if v < min_quality {
+                    errs.push(Violation::min("other", "min_quality", min_quality, v, k));
+                }
+                if v > max_quality {
+                    errs.push(Violation::max("other", "max_quality", max_quality, v, k));
                 }
             }
         }
         if let Some(banned_parts) = &self.banned_parts {
-            if banned_parts.len() > 0 {
-                for (_, v) in &car.raw {
+            if !banned_parts.is_empty() {
+                for v in car.raw.values() {
                     for p in banned_parts {
                         if p.clone().trim() == v {
-                            errs.push(format!("banned part {}", v));
+                            errs.push(Violation::banned("other", "banned_parts", banned_parts, v, "part"));
                         }
                     }
                 }
@@ -64,21 +62,13 @@ impl Others {
         }
         if let Some(required_parts) = &self.required_parts {
             let mut req_parts_clone = required_parts.clone();
-            for (_, v) in &car.raw {
-                for i in 0..req_parts_clone.len() {
-                    if req_parts_clone[i].validate(v.clone()) {
-                        req_parts_clone.remove(i);
-                    }
-                }
+            for v in car.raw.values() {
+                req_parts_clone.retain(|p| !p.validate(v.clone()));
             }
-            if req_parts_clone.len() > 0 {
-                errs.push(format!("parts missing {:?}", req_parts_clone));
+            if !req_parts_clone.is_empty() {
+                errs.push(Violation::new("other", "required_parts", format!("{:?}", required_parts), format!("{:?}", req_parts_clone), format!("parts missing {:?}", req_parts_clone)));
             }
         }
-        if errs.len() > 0 {
-            return Err(CheckError::ErrOthers(errs).into());
-        }
-
-        Ok(())
+        errs
     }
 }
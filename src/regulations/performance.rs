@@ -0,0 +1,73 @@
+use serde::Deserialize;
+
+use crate::specs::Car;
+use super::{Violation, check_max, check_min};
+
+/// Bounds on the figures measured by the game. Times are in seconds, speeds in
+/// km/h. Figures the game did not measure ("???") give a warning instead of a
+/// violation. The exporter writes a time of 999 for runs the car never finished,
+/// e.g. a car too slow to reach 200 km/h, so those break any `max_` time limit.
+/// The braking distance is checked by `[brakes]`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Performance {
+    pub min_time_60: Option<f32>,
+    pub max_time_60: Option<f32>,
+    pub min_time_80: Option<f32>,
+    pub max_time_80: Option<f32>,
+    pub min_time_100: Option<f32>,
+    pub max_time_100: Option<f32>,
+    pub min_time_120: Option<f32>,
+    pub max_time_120: Option<f32>,
+    pub min_time_200: Option<f32>,
+    pub max_time_200: Option<f32>,
+    pub min_time_80_to_120: Option<f32>,
+    pub max_time_80_to_120: Option<f32>,
+    pub min_quarter_mile_time: Option<f32>,
+    pub max_quarter_mile_time: Option<f32>,
+    pub min_quarter_mile_speed: Option<f32>,
+    pub max_quarter_mile_speed: Option<f32>,
+    pub min_kilometer_time: Option<f32>,
+    pub max_kilometer_time: Option<f32>,
+    pub min_kilometer_speed: Option<f32>,
+    pub max_kilometer_speed: Option<f32>,
+    pub min_top_speed: Option<f32>,
+    pub max_top_speed: Option<f32>,
+    pub min_cornering: Option<f32>,
+    pub max_cornering: Option<f32>,
+    pub min_body_roll: Option<f32>,
+    pub max_body_roll: Option<f32>,
+}
+
+impl Performance {
+    pub fn check_car(&self, car: &Car) -> Vec<Violation> {
+        let mut errs = Vec::new();
+        check_min(&mut errs, "performance", "min_time_60", self.min_time_60, car.time_60, "0-60 km/h time");
+        check_max(&mut errs, "performance", "max_time_60", self.max_time_60, car.time_60, "0-60 km/h time");
+        check_min(&mut errs, "performance", "min_time_80", self.min_time_80, car.time_80, "0-80 km/h time");
+        check_max(&mut errs, "performance", "max_time_80", self.max_time_80, car.time_80, "0-80 km/h time");
+        check_min(&mut errs, "performance", "min_time_100", self.min_time_100, car.time_100, "0-100 km/h time");
+        check_max(&mut errs, "performance", "max_time_100", self.max_time_100, car.time_100, "0-100 km/h time");
+        check_min(&mut errs, "performance", "min_time_120", self.min_time_120, car.time_120, "0-120 km/h time");
+        check_max(&mut errs, "performance", "max_time_120", self.max_time_120, car.time_120, "0-120 km/h time");
+        check_min(&mut errs, "performance", "min_time_200", self.min_time_200, car.time_200, "0-200 km/h time");
+        check_max(&mut errs, "performance", "max_time_200", self.max_time_200, car.time_200, "0-200 km/h time");
+        check_min(&mut errs, "performance", "min_time_80_to_120", self.min_time_80_to_120, car.time_80_to_120, "80-120 km/h time");
+        check_max(&mut errs, "performance", "max_time_80_to_120", self.max_time_80_to_120, car.time_80_to_120, "80-120 km/h time");
+        check_min(&mut errs, "performance", "min_quarter_mile_time", self.min_quarter_mile_time, car.quarter_mile_time, "1/4 mile time");
+        check_max(&mut errs, "performance", "max_quarter_mile_time", self.max_quarter_mile_time, car.quarter_mile_time, "1/4 mile time");
+        check_min(&mut errs, "performance", "min_quarter_mile_speed", self.min_quarter_mile_speed, car.quarter_mile_speed_km_h, "1/4 mile speed");
+        check_max(&mut errs, "performance", "max_quarter_mile_speed", self.max_quarter_mile_speed, car.quarter_mile_speed_km_h, "1/4 mile speed");
+        check_min(&mut errs, "performance", "min_kilometer_time", self.min_kilometer_time, car.kilometer_time, "kilometer time");
+        check_max(&mut errs, "performance", "max_kilometer_time", self.max_kilometer_time, car.kilometer_time, "kilometer time");
+        check_min(&mut errs, "performance", "min_kilometer_speed", self.min_kilometer_speed, car.kilometer_speed_km_h, "kilometer speed");
+        check_max(&mut errs, "performance", "max_kilometer_speed", self.max_kilometer_speed, car.kilometer_speed_km_h, "kilometer speed");
+        check_min(&mut errs, "performance", "min_top_speed", self.min_top_speed, car.top_speed_km_h, "top speed");
+        check_max(&mut errs, "performance", "max_top_speed", self.max_top_speed, car.top_speed_km_h, "top speed");
+        check_min(&mut errs, "performance", "min_cornering", self.min_cornering, car.cornering, "cornering");
+        check_max(&mut errs, "performance", "max_cornering", self.max_cornering, car.cornering, "cornering");
+        check_min(&mut errs, "performance", "min_body_roll", self.min_body_roll, car.max_body_roll, "max body roll");
+        check_max(&mut errs, "performance", "max_body_roll", self.max_body_roll, car.max_body_roll, "max body roll");
+        errs
+    }
+}
@@ -0,0 +1,101 @@
+use serde::Deserialize;
+
+use crate::specs::Car;
+use super::{Violation, check_max, check_min};
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PowerUnit {
+    #[default]
+    Kw,
+    Hp,
+    Ps,
+}
+
+impl PowerUnit {
+    /// Converts kW, as exported by the game, into this unit.
+    pub(crate) fn convert_kw(self, kw: f32) -> f32 {
+        match self {
+            Self::Kw => kw,
+            Self::Hp => kw * 1.341_022,
+            Self::Ps => kw * 1.359_622,
+        }
+    }
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Kw => "kW",
+            Self::Hp => "hp",
+            Self::Ps => "PS",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub enum TorqueUnit {
+    #[default]
+    #[serde(rename = "nm")]
+    Nm,
+    #[serde(rename = "lb-ft", alias = "lbft")]
+    LbFt,
+}
+
+impl TorqueUnit {
+    /// Converts Nm, as exported by the game, into this unit.
+    fn convert_nm(self, nm: f32) -> f32 {
+        match self {
+            Self::Nm => nm,
+            Self::LbFt => nm * 0.737_562,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Nm => "Nm",
+            Self::LbFt => "lb-ft",
+        }
+    }
+}
+
+fn round(v: f32) -> f32 {
+    (v * 10.0).round() / 10.0
+}
+
+/// Limits on engine output. Power limits are in `power_unit` (kW by default)
+/// and torque limits in `torque_unit` (Nm by default).
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Power {
+    pub power_unit: Option<PowerUnit>,
+    pub torque_unit: Option<TorqueUnit>,
+
+    pub max_power: Option<f32>,
+    pub max_torque: Option<f32>,
+    /// Power per litre of displacement.
+    pub max_specific_output: Option<f32>,
+    /// Power per tonne of trim weight.
+    pub max_power_to_weight: Option<f32>,
+    pub min_power_rpm: Option<f32>,
+}
+
+impl Power {
+    pub fn check_car(&self, car: &Car) -> Vec<Violation> {
+        let mut errs = Vec::new();
+        let power_unit = self.power_unit.unwrap_or_default();
+        let torque_unit = self.torque_unit.unwrap_or_default();
+        let power = car.peak_power.map(|kw| round(power_unit.convert_kw(kw)));
+        let torque = car.peak_torque.map(|nm| round(torque_unit.convert_nm(nm)));
+        let specific_output = power
+            .filter(|_| car.displacement > 0)
+            .map(|power| round(power / (car.displacement as f32 / 1000.0)));
+        let power_to_weight = power.zip(car.trim_weight.filter(|w| *w > 0.0))
+            .map(|(power, weight)| round(power / (weight / 1000.0)));
+
+        check_max(&mut errs, "power", "max_power", self.max_power, power, &format!("peak power ({})", power_unit.name()));
+        check_max(&mut errs, "power", "max_torque", self.max_torque, torque, &format!("peak torque ({})", torque_unit.name()));
+        check_max(&mut errs, "power", "max_specific_output", self.max_specific_output, specific_output, &format!("specific output ({}/l)", power_unit.name()));
+        check_max(&mut errs, "power", "max_power_to_weight", self.max_power_to_weight, power_to_weight, &format!("power to weight ({}/t)", power_unit.name()));
+        check_min(&mut errs, "power", "min_power_rpm", self.min_power_rpm, car.peak_power_rpm, "peak power rpm");
+        errs
+    }
+}
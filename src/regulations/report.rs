@@ -0,0 +1,156 @@
+use std::fmt::Display;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// A single broken rule. `rule` is the fully qualified key as written in
+/// regulations.toml, e.g. `engine.max_displacement`.
+#[derive(Debug, Clone, Serialize)]
+pub struct Violation {
+    pub section: String,
+    pub rule: String,
+    pub expected: String,
+    pub actual: String,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Violation {
+    pub fn new(section: &str, rule: &str, expected: impl Display, actual: impl Display, message: impl Into<String>) -> Self {
+        Self {
+            section: String::from(section),
+            rule: format!("{}.{}", section, rule),
+            expected: expected.to_string(),
+            actual: actual.to_string(),
+            severity: Severity::Error,
+            message: message.into(),
+        }
+    }
+
+    pub fn min<T: Display>(section: &str, rule: &str, limit: T, actual: T, what: &str) -> Self {
+        let message = format!("{} {} is below the minimum of {}", what, actual, limit);
+        Self::new(section, rule, format!(">= {}", limit), actual, message)
+    }
+
+    pub fn max<T: Display>(section: &str, rule: &str, limit: T, actual: T, what: &str) -> Self {
+        let message = format!("{} {} is above the maximum of {}", what, actual, limit);
+        Self::new(section, rule, format!("<= {}", limit), actual, message)
+    }
+
+    pub fn banned(section: &str, rule: &str, banned: &[String], actual: &str, what: &str) -> Self {
+        let message = format!("banned {} {}", what, actual);
+        Self::new(section, rule, format!("none of {:?}", banned), actual, message)
+    }
+
+    pub fn not_allowed(section: &str, rule: &str, allowed: &[String], actual: &str, what: &str) -> Self {
+        let message = format!("{} {} is not allowed", what, actual);
+        Self::new(section, rule, format!("one of {:?}", allowed), actual, message)
+    }
+
+    /// A limit that could not be checked because the export has no value for it,
+    /// e.g. a missing column or a figure the game did not measure ("???").
+    pub fn unmeasured(section: &str, rule: &str, what: &str) -> Self {
+        let message = format!("{} is missing or was not measured, could not check it", what);
+        Self::new(section, rule, "a value", "???", message).warning()
+    }
+
+    pub fn warning(mut self) -> Self {
+        self.severity = Severity::Warning;
+        self
+    }
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.severity {
+            Severity::Error => write!(f, "[{}] {}", self.rule, self.message),
+            Severity::Warning => write!(f, "[{}] warning: {}", self.rule, self.message),
+        }
+    }
+}
+
+/// Adds a violation if `actual` is not one of `allowed`, ignoring case.
+pub(crate) fn check_allowed(errs: &mut Vec<Violation>, section: &str, rule: &str, allowed: &Option<Vec<String>>, actual: &str, what: &str) {
+    if let Some(allowed) = allowed {
+        if !allowed.iter().any(|a| a.trim().eq_ignore_ascii_case(actual.trim())) {
+            errs.push(Violation::not_allowed(section, rule, allowed, actual, what));
+        }
+    }
+}
+
+/// Adds a violation if `actual` contains any of `banned`, ignoring case.
+pub(crate) fn check_banned(errs: &mut Vec<Violation>, section: &str, rule: &str, banned: &Option<Vec<String>>, actual: &str, what: &str) {
+    if let Some(banned) = banned {
+        if banned.iter().any(|b| actual.to_lowercase().contains(&b.trim().to_lowercase())) {
+            errs.push(Violation::banned(section, rule, banned, actual, what));
+        }
+    }
+}
+
+/// Adds a violation if `actual` is one of `banned`, ignoring case. For closed sets
+/// of names where one can contain another, e.g. Leaded and Unleaded.
+pub(crate) fn check_banned_exact(errs: &mut Vec<Violation>, section: &str, rule: &str, banned: &Option<Vec<String>>, actual: &str, what: &str) {
+    if let Some(banned) = banned {
+        if banned.iter().any(|b| b.trim().eq_ignore_ascii_case(actual.trim())) {
+            errs.push(Violation::banned(section, rule, banned, actual, what));
+        }
+    }
+}
+
+/// Adds a violation if `actual` is below `limit`, or a warning if the export
+/// has no value to check.
+pub(crate) fn check_min<T: Display + PartialOrd>(errs: &mut Vec<Violation>, section: &str, rule: &str, limit: Option<T>, actual: Option<T>, what: &str) {
+    if let Some(limit) = limit {
+        match actual {
+            Some(actual) if actual < limit => errs.push(Violation::min(section, rule, limit, actual, what)),
+            Some(_) => {},
+            None => errs.push(Violation::unmeasured(section, rule, what)),
+        }
+    }
+}
+
+/// Adds a violation if `actual` is above `limit`, or a warning if the export
+/// has no value to check.
+pub(crate) fn check_max<T: Display + PartialOrd>(errs: &mut Vec<Violation>, section: &str, rule: &str, limit: Option<T>, actual: Option<T>, what: &str) {
+    if let Some(limit) = limit {
+        match actual {
+            Some(actual) if actual > limit => errs.push(Violation::max(section, rule, limit, actual, what)),
+            Some(_) => {},
+            None => errs.push(Violation::unmeasured(section, rule, what)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CheckReport {
+    pub violations: Vec<Violation>,
+}
+
+impl CheckReport {
+    pub fn passed(&self) -> bool {
+        self.errors().next().is_none()
+    }
+
+    pub fn errors(&self) -> impl Iterator<Item = &Violation> {
+        self.violations.iter().filter(|v| v.severity == Severity::Error)
+    }
+
+    pub fn extend(&mut self, violations: Vec<Violation>) {
+        self.violations.extend(violations);
+    }
+}
+
+impl Display for CheckReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", if self.passed() { "Ok" } else { "Failed" })?;
+        for violation in &self.violations {
+            write!(f, "\n    {}", violation)?;
+        }
+        Ok(())
+    }
+}
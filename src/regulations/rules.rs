@@ -0,0 +1,222 @@
+use anyhow::{Result, bail};
+use serde::Deserialize;
+
+use super::{CheckReport, Severity, Violation};
+
+/// Boolean groups over named checks. A check is named by its rule key, e.g.
+/// `engine.max_displacement`; bare names like `offroad` refer to `[stats]`.
+/// A check passes when the car has no error for that rule key. A check the export
+/// has no value for (a missing or unmeasured figure) counts as failed.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Rules {
+    /// Each group passes if at least one of its checks passes.
+    #[serde(alias = "or")]
+    pub any_of: Option<Vec<Vec<String>>>,
+    /// Each group passes if all of its checks pass.
+    pub all_of: Option<Vec<Vec<String>>>,
+    /// Each check listed here has to fail.
+    pub not: Option<Vec<String>>,
+    /// Each group passes if at least `n` of its checks pass.
+    pub at_least_n_of: Option<Vec<AtLeastN>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AtLeastN {
+    pub n: usize,
+    pub of: Vec<String>,
+}
+
+fn qualify(name: &str) -> String {
+    match name.trim().split_once('.') {
+        // [body] is another name for [chassis], whose violations are reported as chassis.*
+        Some(("body", key)) => format!("chassis.{}", key),
+        Some(_) => String::from(name.trim()),
+        None => format!("stats.{}", name.trim()),
+    }
+}
+
+impl Rules {
+    /// Every check named by a group.
+    fn names(&self) -> Vec<String> {
+        let mut names = Vec::new();
+        for group in self.any_of.iter().chain(&self.all_of).flatten() {
+            names.extend(group.iter().map(|n| qualify(n)));
+        }
+        names.extend(self.not.iter().flatten().map(|n| qualify(n)));
+        for group in self.at_least_n_of.iter().flatten() {
+            names.extend(group.of.iter().map(|n| qualify(n)));
+        }
+        names
+    }
+
+    /// Makes sure every group only names checks that exist and are configured, so a
+    /// typo or a missing limit can't silently turn a group into a no-op.
+    pub fn validate(&self, known: impl Fn(&str, &str) -> bool, configured: impl Fn(&str, &str) -> bool) -> Result<()> {
+        for name in self.names() {
+            let (section, key) = name.split_once('.').unwrap_or(("", &name));
+            if !known(section, key) {
+                bail!("unknown check `{}` in [rules]", name);
+            }
+            if !configured(section, key) {
+                bail!("check `{}` in [rules] is not set in [{}], so it would never fail", name, section);
+            }
+        }
+        for group in self.at_least_n_of.iter().flatten() {
+            if group.n > group.of.len() {
+                bail!("at_least_n_of group {:?} needs {} checks but only has {}", group.of, group.n, group.of.len());
+            }
+        }
+        Ok(())
+    }
+
+    /// Replaces the errors of every check named in a group with the result of the group.
+    /// Warnings for checks that could not be measured are kept next to the group result.
+    pub fn apply(&self, report: &mut CheckReport) {
+        let names = self.names();
+        let grouped: Vec<Violation> = report.violations.iter()
+            .filter(|v| names.contains(&v.rule))
+            .cloned()
+            .collect();
+        report.violations.retain(|v| v.severity == Severity::Warning || !names.contains(&v.rule));
+
+        for group in self.any_of.iter().flatten() {
+            report.violations.extend(evaluate(&grouped, "any_of", group, 1, &format!("any of {:?}", group)));
+        }
+        for group in self.all_of.iter().flatten() {
+            report.violations.extend(evaluate(&grouped, "all_of", group, group.len(), &format!("all of {:?}", group)));
+        }
+        for group in self.at_least_n_of.iter().flatten() {
+            report.violations.extend(evaluate(&grouped, "at_least_n_of", &group.of, group.n, &format!("at least {} of {:?}", group.n, group.of)));
+        }
+        for name in self.not.iter().flatten() {
+            let name = qualify(name);
+            if !grouped.iter().any(|v| v.rule == name) {
+                report.violations.push(Violation::new("rules", &format!("not({})", name), format!("{} fails", name), "passed", format!("{} must not pass", name)));
+            }
+        }
+    }
+}
+
+/// Checks one group against the violations of its checks. Returns an error if too few
+/// checks passed, or a warning listing the failed checks if the group still passed.
+fn evaluate(violations: &[Violation], kind: &str, group: &[String], needed: usize, expected: &str) -> Option<Violation> {
+    let mut passed = Vec::new();
+    let mut failed = Vec::new();
+    let mut messages = Vec::new();
+    for name in group {
+        let name = qualify(name);
+        let errs: Vec<&Violation> = violations.iter().filter(|v| v.rule == name).collect();
+        if errs.is_empty() {
+            passed.push(name);
+        } else {
+            messages.extend(errs.into_iter().map(|v| v.message.clone()));
+            failed.push(name);
+        }
+    }
+    if failed.is_empty() {
+        return None;
+    }
+
+    let rule = format!("{}({})", kind, group.join("|"));
+    let actual = format!("passed {:?}, failed {:?}", passed, failed);
+    if passed.len() >= needed {
+        let message = format!("satisfied by {}, failed {} ({})", passed.join(", "), failed.join(", "), messages.join(", "));
+        Some(Violation::new("rules", &rule, expected, actual, message).warning())
+    } else {
+        let message = format!("{} of {} checks passed, needed {} ({})", passed.len(), group.len(), needed, messages.join(", "));
+        Some(Violation::new("rules", &rule, expected, actual, message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failed(rule: &str) -> Violation {
+        Violation::max("stats", rule, 1, 2, rule)
+    }
+
+    fn unmeasured(rule: &str) -> Violation {
+        Violation::unmeasured("stats", rule, rule)
+    }
+
+    fn apply(rules: &str, violations: Vec<Violation>) -> CheckReport {
+        let rules: Rules = toml::from_str(rules).unwrap();
+        let mut report = CheckReport { violations };
+        rules.apply(&mut report);
+        report
+    }
+
+    fn rules_of(report: &CheckReport) -> Vec<(&str, Severity)> {
+        report.violations.iter().map(|v| (v.rule.as_str(), v.severity)).collect()
+    }
+
+    #[test]
+    fn any_of() {
+        let report = apply("any_of = [[\"offroad\", \"sportiness\"]]", vec![]);
+        assert!(report.violations.is_empty());
+
+        let report = apply("any_of = [[\"offroad\", \"sportiness\"]]", vec![failed("offroad")]);
+        assert!(report.passed());
+        assert_eq!(rules_of(&report), vec![("rules.any_of(offroad|sportiness)", Severity::Warning)]);
+
+        let report = apply("any_of = [[\"offroad\", \"sportiness\"]]", vec![failed("offroad"), failed("sportiness")]);
+        assert!(!report.passed());
+        assert_eq!(rules_of(&report), vec![("rules.any_of(offroad|sportiness)", Severity::Error)]);
+    }
+
+    #[test]
+    fn all_of() {
+        let report = apply("all_of = [[\"offroad\", \"sportiness\"]]", vec![failed("safety")]);
+        assert_eq!(rules_of(&report), vec![("stats.safety", Severity::Error)]);
+
+        let report = apply("all_of = [[\"offroad\", \"sportiness\"]]", vec![failed("sportiness")]);
+        assert_eq!(rules_of(&report), vec![("rules.all_of(offroad|sportiness)", Severity::Error)]);
+    }
+
+    #[test]
+    fn not() {
+        let report = apply("not = [\"offroad\"]", vec![failed("offroad")]);
+        assert!(report.violations.is_empty());
+
+        let report = apply("not = [\"offroad\"]", vec![]);
+        assert_eq!(rules_of(&report), vec![("rules.not(stats.offroad)", Severity::Error)]);
+    }
+
+    #[test]
+    fn at_least_n_of() {
+        let rules = "at_least_n_of = [{ n = 2, of = [\"offroad\", \"sportiness\", \"safety\"] }]";
+        let report = apply(rules, vec![failed("safety")]);
+        assert!(report.passed());
+        assert_eq!(rules_of(&report), vec![("rules.at_least_n_of(offroad|sportiness|safety)", Severity::Warning)]);
+
+        let report = apply(rules, vec![failed("safety"), failed("offroad")]);
+        assert!(!report.passed());
+    }
+
+    #[test]
+    fn unmeasured_member_counts_as_failed() {
+        let report = apply("any_of = [[\"offroad\", \"sportiness\"]]", vec![failed("offroad"), unmeasured("sportiness")]);
+        assert!(!report.passed());
+        assert_eq!(rules_of(&report), vec![
+            ("stats.sportiness", Severity::Warning),
+            ("rules.any_of(offroad|sportiness)", Severity::Error),
+        ]);
+
+        let report = apply("not = [\"offroad\"]", vec![unmeasured("offroad")]);
+        assert!(report.passed());
+    }
+
+    #[test]
+    fn validate() {
+        let rules: Rules = toml::from_str("any_of = [[\"offroad\", \"engine.max_rpm\"]]").unwrap();
+        assert!(rules.validate(|_, _| true, |_, _| true).is_ok());
+        assert!(rules.validate(|section, _| section == "stats", |_, _| true).is_err());
+        assert!(rules.validate(|_, _| true, |section, _| section == "stats").is_err());
+
+        let rules: Rules = toml::from_str("at_least_n_of = [{ n = 3, of = [\"offroad\", \"safety\"] }]").unwrap();
+        assert!(rules.validate(|_, _| true, |_, _| true).is_err());
+    }
+}
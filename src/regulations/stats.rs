@@ -1,16 +1,10 @@
-use std::collections::HashMap;
-use anyhow::Result;
 use serde::Deserialize;
 
 use crate::specs::Car;
-use super::CheckError;
-
-#[derive(Debug, Deserialize)]
-pub struct Rules {
-    pub or: Option<Vec<Vec<String>>>
-}
+use super::Violation;
 
 #[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct Stats {
     pub model_min_year: Option<usize>,
     pub model_max_year: Option<usize>,
@@ -23,103 +17,132 @@ pub struct Stats {
     pub comfort: Option<f32>,
     pub prestige: Option<f32>,
     pub offroad: Option<f32>,
+
+    pub max_drivability: Option<f32>,
+    pub max_sportiness: Option<f32>,
+    pub max_reliability: Option<f32>,
+    pub max_safety: Option<f32>,
+    pub max_practicality: Option<f32>,
+    pub max_comfort: Option<f32>,
+    pub max_prestige: Option<f32>,
+    pub max_offroad: Option<f32>,
+
     pub cost: Option<f32>,
     pub service_cost: Option<f32>,
     pub fuel_economy: Option<f32>,
 }
 
 impl Stats {
-    pub fn check_car(&self, car: &Car, rules: &Option<Rules>) -> Result<()> {
-        let mut result_map: HashMap<String, bool> = HashMap::new();
-        result_map.insert(String::from("model_min_year"), true);
-        result_map.insert(String::from("model_max_year"), true);
-
-        result_map.insert(String::from("drivability"), true);
-        result_map.insert(String::from("sportiness"), true);
-        result_map.insert(String::from("reliability"), true);
-        result_map.insert(String::from("safety"), true);
-        result_map.insert(String::from("practicality"), true);
-        result_map.insert(String::from("comfort"), true);
-        result_map.insert(String::from("prestige"), true);
-        result_map.insert(String::from("offroad"), true);
-        result_map.insert(String::from("cost"), true);
-        result_map.insert(String::from("serive_cost"), true);
-        result_map.insert(String::from("fuel_economy"), true);
-
+    pub fn check_car(&self, car: &Car) -> Vec<Violation> {
+        let mut errs = Vec::new();
         if let Some(model_min_year) = self.model_min_year {
-            if car.model_year < model_min_year { result_map.insert(String::from("model_min_year"), false); }
+            if car.model_year < model_min_year {
+                errs.push(Violation::min("stats", "model_min_year", model_min_year, car.model_year, "model year"));
+            }
         }
         if let Some(model_max_year) = self.model_max_year {
-            if car.model_year > model_max_year { result_map.insert(String::from("model_max_year"), false); }
+            if car.model_year > model_max_year {
+                errs.push(Violation::max("stats", "model_max_year", model_max_year, car.model_year, "model year"));
+            }
         }
 
         if let Some(drivability) = self.drivability {
-            if car.drivability_rating < drivability { result_map.insert(String::from("drivability"), false); }
+            if car.drivability_rating < drivability {
+                errs.push(Violation::min("stats", "drivability", drivability, car.drivability_rating, "drivability rating"));
+            }
         }
         if let Some(sportiness) = self.sportiness {
-            if car.sportiness_rating < sportiness { result_map.insert(String::from("sportiness"), false); }
+            if car.sportiness_rating < sportiness {
+                errs.push(Violation::min("stats", "sportiness", sportiness, car.sportiness_rating, "sportiness rating"));
+            }
         }
         if let Some(reliability) = self.reliability {
-            if car.reliability_rating < reliability { result_map.insert(String::from("reliability"), false); }
+            if car.reliability_rating < reliability {
+                errs.push(Violation::min("stats", "reliability", reliability, car.reliability_rating, "reliability rating"));
+            }
         }
         if let Some(safety) = self.safety {
-            if car.safety_rating < safety { result_map.insert(String::from("safety"), false); }
+            if car.safety_rating < safety {
+                errs.push(Violation::min("stats", "safety", safety, car.safety_rating, "safety rating"));
+            }
         }
         if let Some(practicality) = self.practicality {
-            if car.practicality_rating < practicality { result_map.insert(String::from("practicality"), false); }
+            if car.practicality_rating < practicality {
+                errs.push(Violation::min("stats", "practicality", practicality, car.practicality_rating, "practicality rating"));
+            }
         }
         if let Some(comfort) = self.comfort {
-            if car.comfort_rating < comfort { result_map.insert(String::from("comfort"), false); }
+            if car.comfort_rating < comfort {
+                errs.push(Violation::min("stats", "comfort", comfort, car.comfort_rating, "comfort rating"));
+            }
         }
         if let Some(prestige) = self.prestige {
-            if car.prestige_rating < prestige { result_map.insert(String::from("prestige"), false); }
+            if car.prestige_rating < prestige {
+                errs.push(Violation::min("stats", "prestige", prestige, car.prestige_rating, "prestige rating"));
+            }
         }
         if let Some(offroad) = self.offroad {
-            if car.offroad_rating < offroad { result_map.insert(String::from("offroad"), false); }
+            if car.offroad_rating < offroad {
+                errs.push(Violation::min("stats", "offroad", offroad, car.offroad_rating, "offroad rating"));
+            }
+        }
+
+        if let Some(max_drivability) = self.max_drivability {
+            if car.drivability_rating > max_drivability {
+                errs.push(Violation::max("stats", "max_drivability", max_drivability, car.drivability_rating, "drivability rating"));
+            }
+        }
+        if let Some(max_sportiness) = self.max_sportiness {
+            if car.sportiness_rating > max_sportiness {
+                errs.push(Violation::max("stats", "max_sportiness", max_sportiness, car.sportiness_rating, "sportiness rating"));
+            }
+        }
+        if let Some(max_reliability) = self.max_reliability {
+            if car.reliability_rating > max_reliability {
+                errs.push(Violation::max("stats", "max_reliability", max_reliability, car.reliability_rating, "reliability rating"));
+            }
+        }
+        if let Some(max_safety) = self.max_safety {
+            if car.safety_rating > max_safety {
+                errs.push(Violation::max("stats", "max_safety", max_safety, car.safety_rating, "safety rating"));
+            }
+        }
+        if let Some(max_practicality) = self.max_practicality {
+            if car.practicality_rating > max_practicality {
+                errs.push(Violation::max("stats", "max_practicality", max_practicality, car.practicality_rating, "practicality rating"));
+            }
+        }
+        if let Some(max_comfort) = self.max_comfort {
+            if car.comfort_rating > max_comfort {
+                errs.push(Violation::max("stats", "max_comfort", max_comfort, car.comfort_rating, "comfort rating"));
+            }
+        }
+        if let Some(max_prestige) = self.max_prestige {
+            if car.prestige_rating > max_prestige {
+                errs.push(Violation::max("stats", "max_prestige", max_prestige, car.prestige_rating, "prestige rating"));
+            }
         }
+        if let Some(max_offroad) = self.max_offroad {
+            if car.offroad_rating > max_offroad {
+                errs.push(Violation::max("stats", "max_offroad", max_offroad, car.offroad_rating, "offroad rating"));
+            }
+        }
+
         if let Some(cost) = self.cost {
-            if car.cost > cost { result_map.insert(String::from("cost"), false); }
+            if car.cost > cost {
+                errs.push(Violation::max("stats", "cost", cost, car.cost, "cost"));
+            }
         }
         if let Some(service_cost) = self.service_cost {
-            if car.service_cost > service_cost { result_map.insert(String::from("service_cost"), false); }
+            if car.service_cost > service_cost {
+                errs.push(Violation::max("stats", "service_cost", service_cost, car.service_cost, "service cost"));
+            }
         }
         if let Some(fuel_economy) = self.fuel_economy {
-            if car.fuel_economy > fuel_economy { result_map.insert(String::from("fuel_economy"), false); }
-        }
-
-        let result_map = match rules {
-            None => {
-                result_map
-            },
-            Some(rules) => {
-                if let Some(or) = &rules.or {
-                    if or.len() == 0 {
-                        result_map
-                    } else {
-                        for rule in or {
-                            let rule_name = rule.join("|");
-                            let mut failed = false;
-                            for key in rule {
-                                failed = failed || result_map.get(key).map(|v| *v).unwrap_or(false);
-                                result_map.remove(key);
-                            }
-                            result_map.insert(rule_name, failed);
-                        }
-                        result_map
-                    }
-                } else {
-                    result_map
-                }
-            }
-        };
-
-        let failed: Vec<String> = result_map.iter().flat_map(|(k,v)| {
-            if !v { Some(k.clone()) } else { None }
-        }).collect();
-        if failed.len() > 0 {
-            return Err(CheckError::ErrStats(failed).into());
+            if car.fuel_economy > fuel_economy {
+                errs.push(Violation::max("stats", "fuel_economy", fuel_economy, car.fuel_economy, "fuel economy"));
+            }
         }
-
-        Ok(())
+        errs
     }
 }
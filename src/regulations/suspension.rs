@@ -0,0 +1,42 @@
+use serde::Deserialize;
+
+use crate::specs::Car;
+use super::{Violation, check_allowed, check_banned, check_max, check_min};
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Suspension {
+    pub front_allowed_geometries: Option<Vec<String>>,
+    pub front_banned_geometries: Option<Vec<String>>,
+    pub rear_allowed_geometries: Option<Vec<String>>,
+    pub rear_banned_geometries: Option<Vec<String>>,
+
+    pub banned_springs: Option<Vec<String>>,
+    pub banned_dampers: Option<Vec<String>>,
+    pub banned_sway_bars: Option<Vec<String>>,
+
+    pub min_ride_height: Option<f32>,
+    pub max_ride_height: Option<f32>,
+    /// Largest camber allowed either way, in degrees, on both axles.
+    pub max_camber: Option<f32>,
+}
+
+impl Suspension {
+    pub fn check_car(&self, car: &Car) -> Vec<Violation> {
+        let mut errs = Vec::new();
+        check_allowed(&mut errs, "suspension", "front_allowed_geometries", &self.front_allowed_geometries, &car.front_suspension, "front suspension");
+        check_banned(&mut errs, "suspension", "front_banned_geometries", &self.front_banned_geometries, &car.front_suspension, "front suspension");
+        check_allowed(&mut errs, "suspension", "rear_allowed_geometries", &self.rear_allowed_geometries, &car.rear_suspension, "rear suspension");
+        check_banned(&mut errs, "suspension", "rear_banned_geometries", &self.rear_banned_geometries, &car.rear_suspension, "rear suspension");
+
+        check_banned(&mut errs, "suspension", "banned_springs", &self.banned_springs, &car.springs, "springs");
+        check_banned(&mut errs, "suspension", "banned_dampers", &self.banned_dampers, &car.dampers, "dampers");
+        check_banned(&mut errs, "suspension", "banned_sway_bars", &self.banned_sway_bars, &car.sway_bars, "sway bars");
+
+        check_min(&mut errs, "suspension", "min_ride_height", self.min_ride_height, car.ride_height, "ride height");
+        check_max(&mut errs, "suspension", "max_ride_height", self.max_ride_height, car.ride_height, "ride height");
+        check_max(&mut errs, "suspension", "max_camber", self.max_camber, car.front_camber.map(f32::abs), "front camber");
+        check_max(&mut errs, "suspension", "max_camber", self.max_camber, car.rear_camber.map(f32::abs), "rear camber");
+        errs
+    }
+}
@@ -0,0 +1,67 @@
+use serde::Deserialize;
+
+use crate::specs::Car;
+use super::{PowerUnit, Violation, check_max, check_min};
+
+/// Weight limits, in kg of trim weight.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Weight {
+    pub min_weight: Option<f32>,
+    pub max_weight: Option<f32>,
+    /// Weight distribution tune, in percent.
+    pub min_distribution: Option<f32>,
+    pub max_distribution: Option<f32>,
+    /// Weight optimization tune, 0 to 1.
+    pub max_optimization: Option<f32>,
+
+    /// Minimum kg per unit of peak power, in `[power].power_unit` (kW by default).
+    pub min_weight_per_power: Option<f32>,
+
+    /// Minimum weight by displacement, e.g.
+    /// `[{ max_displacement = 1600, min_weight = 1100 }, { min_weight = 1300 }]`.
+    /// A step without `max_displacement` covers every bigger engine. Engines
+    /// bigger than every step break the rule.
+    pub min_weight_table: Option<Vec<WeightStep>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WeightStep {
+    pub max_displacement: Option<usize>,
+    pub min_weight: f32,
+}
+
+impl Weight {
+    pub fn check_car(&self, car: &Car, power_unit: PowerUnit) -> Vec<Violation> {
+        let mut errs = Vec::new();
+        check_min(&mut errs, "weight", "min_weight", self.min_weight, car.trim_weight, "weight");
+        check_max(&mut errs, "weight", "max_weight", self.max_weight, car.trim_weight, "weight");
+
+        let distribution = car.weight_distribution_tune.map(|d| (d * 1000.0).round() / 10.0);
+        check_min(&mut errs, "weight", "min_distribution", self.min_distribution, distribution, "weight distribution (%)");
+        check_max(&mut errs, "weight", "max_distribution", self.max_distribution, distribution, "weight distribution (%)");
+        check_max(&mut errs, "weight", "max_optimization", self.max_optimization, car.weight_optimization_tune, "weight optimization");
+
+        if let Some(min_weight_per_power) = self.min_weight_per_power {
+            let weight_per_power = car.trim_weight.zip(car.peak_power)
+                .filter(|(_, kw)| *kw > 0.0)
+                .map(|(weight, kw)| (weight / power_unit.convert_kw(kw) * 100.0).round() / 100.0);
+            check_min(&mut errs, "weight", "min_weight_per_power", Some(min_weight_per_power), weight_per_power, &format!("weight per power (kg/{})", power_unit.name()));
+        }
+
+        if let Some(table) = &self.min_weight_table {
+            let mut steps: Vec<&WeightStep> = table.iter().collect();
+            steps.sort_by_key(|s| s.max_displacement.unwrap_or(usize::MAX));
+            match steps.iter().find(|s| s.max_displacement.is_none_or(|max| car.displacement <= max)) {
+                Some(step) => check_min(&mut errs, "weight", "min_weight_table", Some(step.min_weight), car.trim_weight, &format!("weight for {} cc", car.displacement)),
+                None => {
+                    let largest = steps.last().and_then(|s| s.max_displacement).unwrap_or_default();
+                    let message = format!("displacement {} cc is above every step of the weight table, which goes up to {} cc", car.displacement, largest);
+                    errs.push(Violation::new("weight", "min_weight_table", format!("<= {} cc", largest), car.displacement, message));
+                },
+            }
+        }
+        errs
+    }
+}
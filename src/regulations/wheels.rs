@@ -1,21 +1,29 @@
-use anyhow::Result;
 use serde::Deserialize;
 
 use crate::specs::Car;
-use super::CheckError;
+use super::Violation;
 
 #[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct Wheels {
     pub must_match: Option<bool>,
 
+    pub front_min_width: Option<usize>,
     pub front_max_width: Option<usize>,
+    pub front_min_profile: Option<usize>,
     pub front_max_profile: Option<usize>,
+    pub front_min_rim: Option<usize>,
     pub front_max_rim: Option<usize>,
+    pub front_min_wheel_diameter: Option<usize>,
     pub front_wheel_diameter: Option<usize>,
 
+    pub rear_min_width: Option<usize>,
     pub rear_max_width: Option<usize>,
+    pub rear_min_profile: Option<usize>,
     pub rear_max_profile: Option<usize>,
+    pub rear_min_rim: Option<usize>,
     pub rear_max_rim: Option<usize>,
+    pub rear_min_wheel_diameter: Option<usize>,
     pub rear_wheel_diameter: Option<usize>,
 
     pub banned_compounds: Option<Vec<String>>,
@@ -23,73 +31,109 @@ pub struct Wheels {
 }
 
 impl Wheels {
-    pub fn check_car(&self, car: &Car) -> Result<()> {
+    pub fn check_car(&self, car: &Car) -> Vec<Violation> {
         let mut errs = Vec::new();
-        if self.must_match.unwrap_or(false) {
-            if !car.wheels_match() { errs.push(String::from("wheels dont match")); }
+        if self.must_match.unwrap_or(false) && !car.wheels_match() {
+            errs.push(Violation::new("wheels", "must_match", "matching front and rear", "mismatched", "wheels dont match"));
         }
 
+        if let Some(front_min_width) = self.front_min_width {
+            if car.wheels_front_width < front_min_width {
+                errs.push(Violation::min("wheels", "front_min_width", front_min_width, car.wheels_front_width, "front tire width"));
+            }
+        }
         if let Some(front_max_width) = self.front_max_width {
             if car.wheels_front_width > front_max_width {
-                errs.push(format!("front tire width {}", car.wheels_front_width));
+                errs.push(Violation::max("wheels", "front_max_width", front_max_width, car.wheels_front_width, "front tire width"));
+            }
+        }
+        if let Some(front_min_profile) = self.front_min_profile {
+            if car.wheels_front_profile < front_min_profile {
+                errs.push(Violation::min("wheels", "front_min_profile", front_min_profile, car.wheels_front_profile, "front tire profile"));
             }
         }
         if let Some(front_max_profile) = self.front_max_profile {
             if car.wheels_front_profile > front_max_profile {
-                errs.push(format!("front tire profile {}", car.wheels_front_profile));
+                errs.push(Violation::max("wheels", "front_max_profile", front_max_profile, car.wheels_front_profile, "front tire profile"));
+            }
+        }
+        if let Some(front_min_rim) = self.front_min_rim {
+            if car.wheels_front_rim < front_min_rim {
+                errs.push(Violation::min("wheels", "front_min_rim", front_min_rim, car.wheels_front_rim, "front tire rim"));
             }
         }
         if let Some(front_max_rim) = self.front_max_rim {
             if car.wheels_front_rim > front_max_rim {
-                errs.push(format!("front tire rim {}", car.wheels_front_rim));
+                errs.push(Violation::max("wheels", "front_max_rim", front_max_rim, car.wheels_front_rim, "front tire rim"));
+            }
+        }
+        if let Some(front_min_wheel_diameter) = self.front_min_wheel_diameter {
+            if car.wheels_front_diameter < front_min_wheel_diameter {
+                errs.push(Violation::min("wheels", "front_min_wheel_diameter", front_min_wheel_diameter, car.wheels_front_diameter, "front tire diameter"));
             }
         }
         if let Some(front_wheel_diameter) = self.front_wheel_diameter {
             if car.wheels_front_diameter > front_wheel_diameter {
-                errs.push(format!("front tire diameter {}", car.wheels_front_diameter));
+                errs.push(Violation::max("wheels", "front_wheel_diameter", front_wheel_diameter, car.wheels_front_diameter, "front tire diameter"));
             }
         }
 
+        if let Some(rear_min_width) = self.rear_min_width {
+            if car.wheels_rear_width < rear_min_width {
+                errs.push(Violation::min("wheels", "rear_min_width", rear_min_width, car.wheels_rear_width, "rear tire width"));
+            }
+        }
         if let Some(rear_max_width) = self.rear_max_width {
             if car.wheels_rear_width > rear_max_width {
-                errs.push(format!("rear tire width {}", car.wheels_rear_width));
+                errs.push(Violation::max("wheels", "rear_max_width", rear_max_width, car.wheels_rear_width, "rear tire width"));
+            }
+        }
+        if let Some(rear_min_profile) = self.rear_min_profile {
+            if car.wheels_rear_profile < rear_min_profile {
+                errs.push(Violation::min("wheels", "rear_min_profile", rear_min_profile, car.wheels_rear_profile, "rear tire profile"));
             }
         }
         if let Some(rear_max_profile) = self.rear_max_profile {
             if car.wheels_rear_profile > rear_max_profile {
-                errs.push(format!("rear tire profile {}", car.wheels_rear_profile));
+                errs.push(Violation::max("wheels", "rear_max_profile", rear_max_profile, car.wheels_rear_profile, "rear tire profile"));
+            }
+        }
+        if let Some(rear_min_rim) = self.rear_min_rim {
+            if car.wheels_rear_rim < rear_min_rim {
+                errs.push(Violation::min("wheels", "rear_min_rim", rear_min_rim, car.wheels_rear_rim, "rear tire rim"));
             }
         }
         if let Some(rear_max_rim) = self.rear_max_rim {
             if car.wheels_rear_rim > rear_max_rim {
-                errs.push(format!("rear tire rim {}", car.wheels_rear_rim));
+                errs.push(Violation::max("wheels", "rear_max_rim", rear_max_rim, car.wheels_rear_rim, "rear tire rim"));
+            }
+        }
+        if let Some(rear_min_wheel_diameter) = self.rear_min_wheel_diameter {
+            if car.wheels_rear_diameter < rear_min_wheel_diameter {
+                errs.push(Violation::min("wheels", "rear_min_wheel_diameter", rear_min_wheel_diameter, car.wheels_rear_diameter, "rear tire diameter"));
             }
         }
         if let Some(rear_wheel_diameter) = self.rear_wheel_diameter {
             if car.wheels_rear_diameter > rear_wheel_diameter {
-                errs.push(format!("rear tire diameter {}", car.wheels_rear_diameter));
+                errs.push(Violation::max("wheels", "rear_wheel_diameter", rear_wheel_diameter, car.wheels_rear_diameter, "rear tire diameter"));
             }
         }
 
         if let Some(banned_compounds) = &self.banned_compounds {
             for compound in banned_compounds {
                 if compound.clone().trim() == car.wheels_compound.trim() {
-                    errs.push(format!("banned compound {}", car.wheels_compound));
+                    errs.push(Violation::banned("wheels", "banned_compounds", banned_compounds, &car.wheels_compound, "compound"));
                 }
             }
         }
         if let Some(banned_rim_materials) = &self.banned_rim_materials {
             for mat in banned_rim_materials {
                 if car.wheels_rim_material.trim() == mat.trim() {
-                    errs.push(format!("banned rim material {}", car.wheels_rim_material));
+                    errs.push(Violation::banned("wheels", "banned_rim_materials", banned_rim_materials, &car.wheels_rim_material, "rim material"));
                 }
             }
         }
 
-        if errs.len() > 0 {
-            return Err(CheckError::ErrWheels(errs).into());
-        }
-
-        Ok(())
+        errs
     }
 }
@@ -1,26 +1,67 @@
 use std::collections::HashMap;
+use std::io::Read;
 use anyhow::Result;
 
 use serde::{Serialize, Deserialize};
 
-fn parse_float(s: &str) -> Result<f32> {
-    Ok((s.trim().parse::<f32>()? * 10.0).round() / 10.0)
+use crate::{columns, decode, fields};
+
+fn parse_float(column: &str, s: &str) -> Result<f32, ImportError> {
+    match s.trim().parse::<f32>() {
+        Ok(v) => Ok((v * 10.0).round() / 10.0),
+        Err(_) => Err(ImportError::BadNumber { column: String::from(column), value: String::from(s) }),
+    }
 }
 
-fn parse_int(s: &str) -> Result<usize> {
-    Ok(parse_float(s)? as usize)
+fn parse_int(column: &str, s: &str) -> Result<usize, ImportError> {
+    Ok(parse_float(column, s)? as usize)
 }
 
-#[derive(Debug)]
+/// Parses an optional measurement, rounded to 0.001 to drop float noise from the
+/// export. Missing columns and values the game did not measure ("???") are None.
+fn parse_measured(column: &str, s: &str) -> Result<Option<f32>, ImportError> {
+    match s.trim() {
+        "" | "???" => Ok(None),
+        v => v.parse::<f32>().map(|v| Some((v * 1000.0).round() / 1000.0)).map_err(|_| ImportError::BadNumber { column: String::from(column), value: String::from(s) }),
+    }
+}
+
+fn parse_count(column: &str, s: &str) -> Result<Option<usize>, ImportError> {
+    Ok(parse_measured(column, s)?.map(|v| v.round() as usize))
+}
+
+fn normalise(s: &str) -> String {
+    String::from(s.trim())
+}
+
+/// The fuel class from the `Leaded Fuel` column. The game writes TRUE or FALSE;
+/// anything else is taken as the name of the fuel.
+fn fuel_type(leaded_fuel: &str) -> String {
+    match leaded_fuel.trim().to_lowercase().as_str() {
+        "true" => String::from("Leaded"),
+        "false" => String::from("Unleaded"),
+        _ => normalise(leaded_fuel),
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
 pub struct Car {
 // Raw
+    #[serde(skip)]
     pub raw: HashMap<String, String>,
 
+    pub import_warnings: Vec<String>,
+
 // Basic information
+    pub directory: String,
     pub car_name: String,
     pub model_name: String,
+    pub trim_name: String,
     pub model_year: usize,
     pub wheelbase: usize,
+    pub exporter_version: String,
+    pub game_version: String,
+    pub trim_year: Option<usize>,
 
 // Stats
     pub drivability_rating: f32,
@@ -34,6 +75,9 @@ pub struct Car {
     pub cost: f32,
     pub service_cost: f32,
     pub fuel_economy: f32,
+    pub utility_rating: Option<f32>,
+    pub environmental_resistance: Option<f32>,
+    pub trim_emissions: Option<f32>,
 
 // Engine
     pub engine_year: usize,
@@ -51,6 +95,88 @@ pub struct Car {
     pub rpm: usize,
     pub catalytic_converter: String,
     pub headers: String,
+    pub engine_family_name: String,
+    pub engine_family_year: Option<usize>,
+    pub family_quality: Option<f32>,
+    pub family_techpool: Option<f32>,
+    pub family_top_end_techpool: Option<f32>,
+    pub block_type: String,
+    pub block_material: String,
+    pub head_material: String,
+    pub head_type: String,
+    pub variant_name: String,
+    pub variant_top_end_quality: Option<f32>,
+    pub variant_bottom_end_quality: Option<f32>,
+    pub variant_aspiration_quality: Option<f32>,
+    pub variant_fuel_system_quality: Option<f32>,
+    pub variant_exhaust_quality: Option<f32>,
+    pub variant_top_end_techpool: Option<f32>,
+    pub variant_family_techpool: Option<f32>,
+    pub variant_bottom_end_techpool: Option<f32>,
+    pub variant_aspiration_techpool: Option<f32>,
+    pub variant_fuel_system_techpool: Option<f32>,
+    pub variant_exhaust_techpool: Option<f32>,
+    pub variant_bore: Option<f32>,
+    pub variant_stroke: Option<f32>,
+    pub variant_displacement: Option<f32>,
+    pub balance_shaft: String,
+    pub crankshaft: String,
+    pub conrods: String,
+    pub pistons: String,
+    pub cam_profile: Option<f32>,
+    pub valve_spring_stiffness: Option<f32>,
+    pub vvt: String,
+    pub vvl: String,
+    pub intercooler_size: Option<f32>,
+    pub aspiration_item_1: String,
+    pub aspiration_sub_item_1: String,
+    pub aspiration_item_2: String,
+    pub aspiration_sub_item_2: String,
+    pub boost_control: String,
+    pub compressor_size_1: Option<f32>,
+    pub compressor_size_2: Option<f32>,
+    pub turbine_size_1: Option<f32>,
+    pub turbine_size_2: Option<f32>,
+    pub aspiration_tune_1: Option<f32>,
+    pub aspiration_tune_2: Option<f32>,
+    pub fuel_system_type: String,
+    pub fuel_system: String,
+    pub throttle_configuration: String,
+    pub octane_offset: Option<f32>,
+    pub afr: Option<f32>,
+    pub ignition_timing: Option<f32>,
+    pub rpm_limit: Option<f32>,
+    pub exhaust_count: String,
+    pub exhaust_diameter: Option<f32>,
+    pub exhaust_bypass_valves: String,
+    pub muffler_1: String,
+    pub muffler_2: String,
+    pub peak_power: Option<f32>,
+    pub peak_power_rpm: Option<f32>,
+    pub peak_torque: Option<f32>,
+    pub peak_torque_rpm: Option<f32>,
+    pub peak_boost: Option<f32>,
+    pub peak_boost_rpm: Option<f32>,
+    pub idle_rpm: Option<f32>,
+    pub min_economy_rpm: Option<f32>,
+    pub engine_weight: Option<f32>,
+    pub flywheel_weight: Option<f32>,
+    pub required_cooling: Option<f32>,
+    pub engine_performance_index: Option<f32>,
+    pub engine_responsiveness: Option<f32>,
+    pub engine_smoothness: Option<f32>,
+    pub engine_emissions: Option<f32>,
+    pub engine_efficiency: Option<f32>,
+    pub engine_noise: Option<f32>,
+    pub intake_noise: Option<f32>,
+    pub exhaust_noise: Option<f32>,
+    pub engine_ron_rating: Option<f32>,
+    pub crankshaft_max_rpm: Option<f32>,
+    pub crankshaft_max_torque: Option<f32>,
+    pub conrods_max_rpm: Option<f32>,
+    pub conrods_max_torque: Option<f32>,
+    pub pistons_max_rpm: Option<f32>,
+    pub pistons_max_torque: Option<f32>,
 
 // Wheels
     pub wheels_front_width: usize,
@@ -63,9 +189,23 @@ pub struct Car {
     pub wheels_rear_diameter: usize,
     pub wheels_compound: String,
     pub wheels_rim_material: String,
+    pub tyre_type: String,
+    pub front_wheel_offset: Option<f32>,
+    pub rear_wheel_offset: Option<f32>,
+    pub front_wheel_diameter_2: Option<f32>,
+    pub rear_wheel_diameter_2: Option<f32>,
+    pub tyre_speed_index: String,
+    pub tyre_speed_rating_km_h: Option<f32>,
 
 // Drivetrain
     pub drivetrain_type: String,
+    pub gearbox_type: String,
+    pub gear_count: Option<usize>,
+    pub gear_spacing: Option<f32>,
+    pub power_to_front: Option<f32>,
+    pub differential_type: String,
+    pub final_drive: Option<f32>,
+    pub speed_limiter: Option<f32>,
 
 // Part information
     pub chassis_type: String,
@@ -81,64 +221,366 @@ pub struct Car {
     pub model_body_quality: f32,
     pub model_chassis_quality: f32,
     pub model_body_techpool: f32,
+    pub model_chassis_techpool: Option<f32>,
+    pub trim_body_quality: Option<f32>,
+    pub trim_aerodynamics_quality: Option<f32>,
+    pub trim_interior_quality: Option<f32>,
+    pub trim_fixture_quality: Option<f32>,
+    pub trim_tyre_quality: Option<f32>,
+    pub trim_suspension_quality: Option<f32>,
+    pub trim_safety_quality: Option<f32>,
+    pub trim_brake_quality: Option<f32>,
+    pub trim_assist_quality: Option<f32>,
+    pub trim_drivetrain_quality: Option<f32>,
+    pub trim_chassis_techpool: Option<f32>,
+    pub trim_body_techpool: Option<f32>,
+    pub trim_aerodynamics_techpool: Option<f32>,
+    pub trim_interior_techpool: Option<f32>,
+    pub trim_fixture_techpool: Option<f32>,
+    pub trim_tyre_techpool: Option<f32>,
+    pub trim_suspension_techpool: Option<f32>,
+    pub trim_safety_techpool: Option<f32>,
+    pub trim_brake_techpool: Option<f32>,
+    pub trim_assist_techpool: Option<f32>,
+    pub trim_drivetrain_techpool: Option<f32>,
+
+// Body
+    pub body_type: String,
+    pub doors: Option<usize>,
+    pub body_name: String,
+    pub convertible_type: String,
+    pub full_1st_row_seats: Option<usize>,
+    pub small_1st_row_seats: Option<usize>,
+    pub full_2nd_row_seats: Option<usize>,
+    pub small_2nd_row_seats: Option<usize>,
+    pub full_3rd_row_seats: Option<usize>,
+    pub small_3rd_row_seats: Option<usize>,
+    pub passenger_volume: Option<f32>,
+    pub cargo_volume: Option<f32>,
+
+// Aero and cooling
+    pub active_cooling: String,
+    pub cooling_amount: Option<f32>,
+    pub undertray: String,
+    pub active_aero: String,
+    pub rear_wing_angle: Option<f32>,
+    pub front_wing_angle: Option<f32>,
+    pub front_downforce: Option<f32>,
+    pub rear_downforce: Option<f32>,
+
+// Interior and assists
+    pub interior: String,
+    pub entertainment: String,
+    pub power_steering: String,
+    pub assists: String,
+    pub safety: String,
+
+// Suspension
+    pub springs: String,
+    pub front_spring_stiffness: Option<f32>,
+    pub rear_spring_stiffness: Option<f32>,
+    pub dampers: String,
+    pub front_damper_stiffness: Option<f32>,
+    pub rear_damper_stiffness: Option<f32>,
+    pub sway_bars: String,
+    pub front_sway_bar_stiffness: Option<f32>,
+    pub rear_sway_bar_stiffness: Option<f32>,
+    pub ride_height: Option<f32>,
+    pub front_camber: Option<f32>,
+    pub rear_camber: Option<f32>,
+
+// Brakes
+    pub front_brake_type: String,
+    pub rear_brake_type: String,
+    pub front_caliper_pistons: Option<usize>,
+    pub rear_caliper_pistons: Option<usize>,
+    pub front_brake_diameter: Option<f32>,
+    pub rear_brake_diameter: Option<f32>,
+    pub front_brake_pad_type: Option<f32>,
+    pub rear_brake_pad_type: Option<f32>,
+    pub front_brake_force: Option<f32>,
+    pub rear_brake_force: Option<f32>,
+
+// Weight
+    pub weight_optimization_tune: Option<f32>,
+    pub weight_distribution_tune: Option<f32>,
+    pub trim_weight: Option<f32>,
+    pub tow_weight: Option<f32>,
+    pub body_stiffness: Option<f32>,
+
+// Economics
+    pub trim_engineering_time: Option<f32>,
+    pub trim_production_units: Option<f32>,
+    pub trim_price: Option<f32>,
+    pub trim_tooling_costs: Option<f32>,
+    pub trim_total_costs: Option<f32>,
+    pub trim_material_cost: Option<f32>,
+    pub trim_engineering_costs: Option<f32>,
+    pub engine_engineering_time: Option<f32>,
+    pub engine_production_units: Option<f32>,
+    pub engine_engineering_costs: Option<f32>,
+    pub engine_tooling_costs: Option<f32>,
+    pub engine_material_cost: Option<f32>,
+    pub engine_total_cost: Option<f32>,
+    pub engine_service_cost: Option<f32>,
+
+// Performance
+    pub braking_distance: Option<f32>,
+    pub max_body_roll: Option<f32>,
+    pub cornering: Option<f32>,
+    pub top_speed_km_h: Option<f32>,
+    pub top_speed_gear: Option<usize>,
+    pub top_speed_engine_power: Option<f32>,
+    pub top_speed_engine_rpm: Option<f32>,
+    pub time_60: Option<f32>,
+    pub time_80: Option<f32>,
+    pub time_100: Option<f32>,
+    pub time_200: Option<f32>,
+    pub time_120: Option<f32>,
+    pub time_80_to_120: Option<f32>,
+    pub kilometer_time: Option<f32>,
+    pub kilometer_speed_km_h: Option<f32>,
+    pub quarter_mile_time: Option<f32>,
+    pub quarter_mile_speed_km_h: Option<f32>,
+}
+
+/// Name of the file in a submission folder holding the trim name of the entry,
+/// for exports that contain more than one trim.
+pub const ENTRY_MARKER: &str = "entry.txt";
+
+/// Finds the trim called `trim_name`, ignoring case, or the first one if no name is given.
+fn pick_trim<T>(trims: Vec<T>, trim_name: Option<&str>, name: impl Fn(&T) -> &String) -> Result<T, ImportError> {
+    match trim_name {
+        Some(trim_name) => trims.into_iter()
+            .find(|trim| name(trim).trim().eq_ignore_ascii_case(trim_name))
+            .ok_or_else(|| ImportError::UnknownTrim(String::from(trim_name))),
+        None => trims.into_iter().next().ok_or(ImportError::NoData),
+    }
 }
 
 impl Car {
-    pub fn from_directory(path: &str) -> Result<Self> {
-        let raw = RawCar::from_directory(path)?;
+    /// Imports the entry trim exported to `<path>/<folder name>.csv`, picked by
+    /// the trim name in the folder's ENTRY_MARKER file if there is one.
+    pub fn from_directory(path: &str) -> Result<Self, ImportError> {
+        let trims = RawCar::from_directory(path)?;
+        let marker = std::fs::read_to_string(std::path::Path::new(path).join(ENTRY_MARKER)).ok();
+        // An empty marker file is the same as no marker
+        let trim_name = marker.as_deref().map(str::trim).filter(|name| !name.is_empty());
+        Self::from_raw_trims(trims, trim_name, path)
+    }
+
+    /// Imports the first trim from an exported csv file in any supported encoding.
+    pub fn from_csv_reader<R: Read>(reader: R) -> Result<Self, ImportError> {
+        Self::from_raw_trims(RawCar::from_csv_reader(reader)?, None, "")
+    }
+
+    /// Imports the first trim from already decoded csv text.
+    pub fn from_csv_str(text: &str) -> Result<Self, ImportError> {
+        Self::from_raw_trims(RawCar::from_csv_str(text)?, None, "")
+    }
+
+    /// Picks a trim before importing it, so other trims of the export that fail
+    /// to import don't reject the entry.
+    fn from_raw_trims(trims: Vec<RawCar>, trim_name: Option<&str>, directory: &str) -> Result<Self, ImportError> {
+        let count = trims.len();
+        let raw = pick_trim(trims, trim_name, |raw| &raw.trim_name)?;
+        let mut car = Self::from_raw(raw, directory)?;
+        if trim_name.is_none() {
+            car.warn_other_trims(count);
+        }
+        Ok(car)
+    }
+
+    /// Imports every trim exported to `<path>/<folder name>.csv`.
+    pub fn trims_from_directory(path: &str) -> Result<Vec<Self>, ImportError> {
+        RawCar::from_directory(path)?.into_iter().map(|raw| Self::from_raw(raw, path)).collect()
+    }
+
+    /// Imports every trim from an exported csv file in any supported encoding.
+    pub fn trims_from_csv_reader<R: Read>(reader: R) -> Result<Vec<Self>, ImportError> {
+        RawCar::from_csv_reader(reader)?.into_iter().map(|raw| Self::from_raw(raw, "")).collect()
+    }
+
+    /// Imports every trim from already decoded csv text.
+    pub fn trims_from_csv_str(text: &str) -> Result<Vec<Self>, ImportError> {
+        RawCar::from_csv_str(text)?.into_iter().map(|raw| Self::from_raw(raw, "")).collect()
+    }
+
+    /// Picks the trim called `trim_name`, or the first one if no name is given.
+    pub fn select_trim(trims: Vec<Self>, trim_name: Option<&str>) -> Result<Self, ImportError> {
+        let count = trims.len();
+        let mut car = pick_trim(trims, trim_name, |car| &car.trim_name)?;
+        if trim_name.is_none() {
+            car.warn_other_trims(count);
+        }
+        Ok(car)
+    }
+
+    fn warn_other_trims(&mut self, count: usize) {
+        if count > 1 {
+            self.import_warnings.push(format!("export has {} trims, checked the first one ({}); put the entry's trim name in {} to pick another", count, self.trim_name, ENTRY_MARKER));
+        }
+    }
+
+    fn from_raw(raw: RawCar, directory: &str) -> Result<Self, ImportError> {
         Ok(Self {
-            raw: RawCar::to_hashmap(raw.clone()),
+            raw: raw.to_hashmap().map_err(|e| ImportError::BadCsv(e.to_string()))?,
+            import_warnings: raw.import_warnings.clone(),
 
         // Basic information
+            directory: String::from(directory),
             car_name: raw.car_name,
             model_name: raw.model_name,
-            model_year: parse_int(&raw.model_year)?,
-            wheelbase: parse_int(&raw.wheelbase)?,
+            trim_name: raw.trim_name,
+            model_year: parse_int("Model Year", &raw.model_year)?,
+            wheelbase: parse_int("Wheelbase", &raw.wheelbase)?,
+            exporter_version: normalise(&raw.exporter_version),
+            game_version: normalise(&raw.game_version),
+            trim_year: parse_count("Trim Year", &raw.trim_year)?,
 
         // Stats
-            drivability_rating: parse_float(&raw.drivability_rating)?,
-            sportiness_rating: parse_float(&raw.sportiness_rating)?,
-            reliability_rating: parse_float(&raw.trim_reliability)?,
-            safety_rating: parse_float(&raw.safety_rating)?,
-            practicality_rating: parse_float(&raw.practicality_rating)?,
-            comfort_rating: parse_float(&raw.comfort_rating)?,
-            prestige_rating: parse_float(&raw.prestige_rating)?,
-            offroad_rating: parse_float(&raw.offroad_rating)?,
-            cost: parse_float(&raw.trim_cost)?,
-            service_cost: parse_float(&raw.trim_service_costs)?,
-            fuel_economy: parse_float(&raw.trim_economy)?,
+            drivability_rating: parse_float("Drivability Rating", &raw.drivability_rating)?,
+            sportiness_rating: parse_float("Sportiness Rating", &raw.sportiness_rating)?,
+            reliability_rating: parse_float("Trim Reliability", &raw.trim_reliability)?,
+            safety_rating: parse_float("Safety Rating", &raw.safety_rating)?,
+            practicality_rating: parse_float("Practicality Rating", &raw.practicality_rating)?,
+            comfort_rating: parse_float("Comfort Rating", &raw.comfort_rating)?,
+            prestige_rating: parse_float("Prestige Rating", &raw.prestige_rating)?,
+            offroad_rating: parse_float("Offroad Rating", &raw.offroad_rating)?,
+            cost: parse_float("Trim Cost", &raw.trim_cost)?,
+            service_cost: parse_float("Trim Service Costs", &raw.trim_service_costs)?,
+            fuel_economy: parse_float("Trim Economy", &raw.trim_economy)?,
+            utility_rating: parse_measured("Utility Rating", &raw.utility_rating)?,
+            environmental_resistance: parse_measured("Environmental Resistance", &raw.environmental_resistance)?,
+            trim_emissions: parse_measured("Trim Emissions", &raw.trim_emissions)?,
 
         // Engine
-            engine_year: parse_int(&raw.variant_year)?,
-            cylinder_count: parse_int(&raw.cylinder_count)?,
+            engine_year: parse_int("Variant Year", &raw.variant_year)?,
+            cylinder_count: parse_int("Cylinder Count", &raw.cylinder_count)?,
             aspiration: raw.aspiration,
-            octane: parse_float(&raw.fuel_octane)?,
-            fuel_type: if raw.leaded_fuel.to_lowercase().contains("false") { String::from("leaded") } else { String::from("unleaded") },
-            displacement: (parse_float(&raw.family_displacement)? * 1000.0) as usize,
-            stroke: parse_float(&raw.family_stroke)?,
-            bore: parse_float(&raw.family_bore)?,
+            octane: parse_float("Fuel Octane", &raw.fuel_octane)?,
+            fuel_type: fuel_type(&raw.leaded_fuel),
+            displacement: (parse_float("Family Displacement", &raw.family_displacement)? * 1000.0) as usize,
+            stroke: parse_float("Family Stroke", &raw.family_stroke)?,
+            bore: parse_float("Family Bore", &raw.family_bore)?,
             intake_type: raw.intake,
-            intake_valves: parse_int(&raw.intake_valves)?,
-            exhaust_valves: parse_int(&raw.exhaust_valves)?,
-            compression: parse_float(&raw.compression)?,
-            rpm: parse_int(&raw.max_rpm)?,
+            intake_valves: parse_int("Intake Valves", &raw.intake_valves)?,
+            exhaust_valves: parse_int("Exhaust Valves", &raw.exhaust_valves)?,
+            compression: parse_float("Compression", &raw.compression)?,
+            rpm: parse_int("Max RPM", &raw.max_rpm)?,
             catalytic_converter: raw.catalytic_converter,
             headers: raw.headers,
+            engine_family_name: normalise(&raw.engine_family_name),
+            engine_family_year: parse_count("Engine Family Year", &raw.engine_family_year)?,
+            family_quality: parse_measured("Family Quality", &raw.family_quality)?,
+            family_techpool: parse_measured("Family Techpool", &raw.family_techpool)?,
+            family_top_end_techpool: parse_measured("Family Top End Techpool", &raw.family_top_end_techpool)?,
+            block_type: normalise(&raw.block_type),
+            block_material: normalise(&raw.block_material),
+            head_material: normalise(&raw.head_material),
+            head_type: normalise(&raw.head_type),
+            variant_name: normalise(&raw.variant_name),
+            variant_top_end_quality: parse_measured("Variant Top End Quality", &raw.variant_top_end_quality)?,
+            variant_bottom_end_quality: parse_measured("Variant Bottom End Quality", &raw.variant_bottom_end_quality)?,
+            variant_aspiration_quality: parse_measured("Variant Aspiration Quality", &raw.variant_aspiration_quality)?,
+            variant_fuel_system_quality: parse_measured("Variant Fuel System Quality", &raw.variant_fuel_system_quality)?,
+            variant_exhaust_quality: parse_measured("Variant Exhaust Quality", &raw.variant_exhaust_quality)?,
+            variant_top_end_techpool: parse_measured("Variant Top End Techpool", &raw.variant_top_end_techpool)?,
+            variant_family_techpool: parse_measured("Variant Family Techpool", &raw.variant_family_techpool)?,
+            variant_bottom_end_techpool: parse_measured("Variant Bottom End Techpool", &raw.variant_bottom_end_techpool)?,
+            variant_aspiration_techpool: parse_measured("Variant Aspiration Techpool", &raw.variant_aspiration_techpool)?,
+            variant_fuel_system_techpool: parse_measured("Variant Fuel System Techpool", &raw.variant_fuel_system_techpool)?,
+            variant_exhaust_techpool: parse_measured("Variant Exhaust Techpool", &raw.variant_exhaust_techpool)?,
+            variant_bore: parse_measured("Variant Bore", &raw.variant_bore)?,
+            variant_stroke: parse_measured("Variant Stroke", &raw.variant_stroke)?,
+            variant_displacement: parse_measured("Variant Displacement", &raw.variant_displacement)?,
+            balance_shaft: normalise(&raw.balance_shaft),
+            crankshaft: normalise(&raw.crankshaft),
+            conrods: normalise(&raw.conrods),
+            pistons: normalise(&raw.pistons),
+            cam_profile: parse_measured("Cam Profile", &raw.cam_profile)?,
+            valve_spring_stiffness: parse_measured("Valve Spring Stiffness", &raw.valve_spring_stiffness)?,
+            vvt: normalise(&raw.vvt),
+            vvl: normalise(&raw.vvl),
+            intercooler_size: parse_measured("Intercooler Size", &raw.intercooler_size)?,
+            aspiration_item_1: normalise(&raw.aspiration_item_1),
+            aspiration_sub_item_1: normalise(&raw.aspiration_sub_item_1),
+            aspiration_item_2: normalise(&raw.aspiration_item_2),
+            aspiration_sub_item_2: normalise(&raw.aspiration_sub_item_2),
+            boost_control: normalise(&raw.boost_control),
+            compressor_size_1: parse_measured("Compressor Size 1", &raw.compressor_size_1)?,
+            compressor_size_2: parse_measured("Compressor Size 2", &raw.compressor_size_2)?,
+            turbine_size_1: parse_measured("Turbine Size 1", &raw.turbine_size_1)?,
+            turbine_size_2: parse_measured("Turbine Size 2", &raw.turbine_size_2)?,
+            aspiration_tune_1: parse_measured("Aspiration Tune 1", &raw.aspiration_tune_1)?,
+            aspiration_tune_2: parse_measured("Aspiration Tune 2", &raw.aspiration_tune_2)?,
+            fuel_system_type: normalise(&raw.fuel_system_type),
+            fuel_system: normalise(&raw.fuel_system),
+            throttle_configuration: normalise(&raw.throttle_configuration),
+            octane_offset: parse_measured("Octane Offset", &raw.octane_offset)?,
+            afr: parse_measured("AFR", &raw.afr)?,
+            ignition_timing: parse_measured("Ignition Timing", &raw.ignition_timing)?,
+            rpm_limit: parse_measured("RPM Limit", &raw.rpm_limit)?,
+            exhaust_count: normalise(&raw.exhaust_count),
+            exhaust_diameter: parse_measured("Exhaust Diameter", &raw.exhaust_diameter)?,
+            exhaust_bypass_valves: normalise(&raw.exhaust_bypass_valves),
+            muffler_1: normalise(&raw.muffler_1),
+            muffler_2: normalise(&raw.muffler_2),
+            peak_power: parse_measured("Peak Power", &raw.peak_power)?,
+            peak_power_rpm: parse_measured("Peak Power RPM", &raw.peak_power_rpm)?,
+            peak_torque: parse_measured("Peak Torque", &raw.peak_torque)?,
+            peak_torque_rpm: parse_measured("Peak Torque RPM", &raw.peak_torque_rpm)?,
+            peak_boost: parse_measured("Peak Boost", &raw.peak_boost)?,
+            peak_boost_rpm: parse_measured("Peak Boost RPM", &raw.peak_boost_rpm)?,
+            idle_rpm: parse_measured("Idle RPM", &raw.idle_rpm)?,
+            min_economy_rpm: parse_measured("Min Economy RPM", &raw.min_economy_rpm)?,
+            engine_weight: parse_measured("Engine Weight", &raw.engine_weight)?,
+            flywheel_weight: parse_measured("Flywheel Weight", &raw.flywheel_weight)?,
+            required_cooling: parse_measured("Required Cooling", &raw.required_cooling)?,
+            engine_performance_index: parse_measured("Engine Performance Index", &raw.engine_performance_index)?,
+            engine_responsiveness: parse_measured("Engine Responsiveness", &raw.engine_responsiveness)?,
+            engine_smoothness: parse_measured("Engine Smoothness", &raw.engine_smoothness)?,
+            engine_emissions: parse_measured("Engine Emissions", &raw.engine_emissions)?,
+            engine_efficiency: parse_measured("Engine Efficiency", &raw.engine_efficiency)?,
+            engine_noise: parse_measured("Engine Noise", &raw.engine_noise)?,
+            intake_noise: parse_measured("Intake Noise", &raw.intake_noise)?,
+            exhaust_noise: parse_measured("Exhaust Noise", &raw.exhaust_noise)?,
+            engine_ron_rating: parse_measured("Engine RON Rating", &raw.engine_ron_rating)?,
+            crankshaft_max_rpm: parse_measured("Crankshaft Max RPM", &raw.crankshaft_max_rpm)?,
+            crankshaft_max_torque: parse_measured("Crankshaft Max Torque", &raw.crankshaft_max_torque)?,
+            conrods_max_rpm: parse_measured("Conrods Max RPM", &raw.conrods_max_rpm)?,
+            conrods_max_torque: parse_measured("Conrods Max Torque", &raw.conrods_max_torque)?,
+            pistons_max_rpm: parse_measured("Pistons Max RPM", &raw.pistons_max_rpm)?,
+            pistons_max_torque: parse_measured("Pistons Max Torque", &raw.pistons_max_torque)?,
 
         // Wheels
-            wheels_front_width: parse_int(&raw.front_tyre_width)?,
-            wheels_front_profile: parse_int(&raw.front_tyre_profile)?,
-            wheels_front_rim: parse_int(&raw.front_rim_size)?,
-            wheels_front_diameter: parse_int(&raw.front_wheel_diameter)?,
-            wheels_rear_width: parse_int(&raw.rear_tyre_width)?,
-            wheels_rear_profile: parse_int(&raw.rear_tyre_profile)?,
-            wheels_rear_rim: parse_int(&raw.rear_rim_size)?,
-            wheels_rear_diameter: parse_int(&raw.rear_wheel_diameter)?,
+            wheels_front_width: parse_int("Front Tyre Width", &raw.front_tyre_width)?,
+            wheels_front_profile: parse_int("Front Tyre Profile", &raw.front_tyre_profile)?,
+            wheels_front_rim: parse_int("Front Rim Size", &raw.front_rim_size)?,
+            wheels_front_diameter: parse_int("Front Wheel Diameter", &raw.front_wheel_diameter)?,
+            wheels_rear_width: parse_int("Rear Tyre Width", &raw.rear_tyre_width)?,
+            wheels_rear_profile: parse_int("Rear Tyre Profile", &raw.rear_tyre_profile)?,
+            wheels_rear_rim: parse_int("Rear Rim Size", &raw.rear_rim_size)?,
+            wheels_rear_diameter: parse_int("Rear Wheel Diameter", &raw.rear_wheel_diameter)?,
             wheels_compound: raw.tyre_compound,
             wheels_rim_material: raw.rim_material,
+            tyre_type: normalise(&raw.tyre_type),
+            front_wheel_offset: parse_measured("Front Wheel Offset", &raw.front_wheel_offset)?,
+            rear_wheel_offset: parse_measured("Rear Wheel Offset", &raw.rear_wheel_offset)?,
+            front_wheel_diameter_2: parse_measured("Front Wheel Diameter 2", &raw.front_wheel_diameter_2)?,
+            rear_wheel_diameter_2: parse_measured("Rear Wheel Diameter 2", &raw.rear_wheel_diameter_2)?,
+            tyre_speed_index: normalise(&raw.tyre_speed_index),
+            tyre_speed_rating_km_h: parse_measured("Tyre Speed Rating (km/h)", &raw.tyre_speed_rating_km_h)?,
 
         // Drivetrain
             drivetrain_type: raw.drive_type,
+            gearbox_type: normalise(&raw.gearbox_type),
+            gear_count: parse_count("Gear Count", &raw.gear_count)?,
+            gear_spacing: parse_measured("Gear Spacing", &raw.gear_spacing)?,
+            power_to_front: parse_measured("Power To Front", &raw.power_to_front)?,
+            differential_type: normalise(&raw.differential_type),
+            final_drive: parse_measured("Final Drive", &raw.final_drive)?,
+            speed_limiter: parse_measured("Speed Limiter", &raw.speed_limiter)?,
 
         // Part information
             chassis_type: raw.chassis_type,
@@ -151,14 +593,161 @@ impl Car {
             engine_placement: raw.engine_placement,
             engine_orientation: raw.engine_orientation,
 
-            model_body_quality: parse_float(&raw.model_body_quality)?,
-            model_chassis_quality: parse_float(&raw.model_chassis_quality)?,
-            model_body_techpool: parse_float(&raw.model_body_techpool)?,
+            model_body_quality: parse_float("Model Body Quality", &raw.model_body_quality)?,
+            model_chassis_quality: parse_float("Model Chassis Quality", &raw.model_chassis_quality)?,
+            model_body_techpool: parse_float("Model Body Techpool", &raw.model_body_techpool)?,
+            model_chassis_techpool: parse_measured("Model Chassis Techpool", &raw.model_chassis_techpool)?,
+            trim_body_quality: parse_measured("Trim Body Quality", &raw.trim_body_quality)?,
+            trim_aerodynamics_quality: parse_measured("Trim Aerodynamics Quality", &raw.trim_aerodynamics_quality)?,
+            trim_interior_quality: parse_measured("Trim Interior Quality", &raw.trim_interior_quality)?,
+            trim_fixture_quality: parse_measured("Trim Fixture Quality", &raw.trim_fixture_quality)?,
+            trim_tyre_quality: parse_measured("Trim Tyre Quality", &raw.trim_tyre_quality)?,
+            trim_suspension_quality: parse_measured("Trim Suspension Quality", &raw.trim_suspension_quality)?,
+            trim_safety_quality: parse_measured("Trim Safety Quality", &raw.trim_safety_quality)?,
+            trim_brake_quality: parse_measured("Trim Brake Quality", &raw.trim_brake_quality)?,
+            trim_assist_quality: parse_measured("Trim Assist Quality", &raw.trim_assist_quality)?,
+            trim_drivetrain_quality: parse_measured("Trim Drivetrain Quality", &raw.trim_drivetrain_quality)?,
+            trim_chassis_techpool: parse_measured("Trim Chassis Techpool", &raw.trim_chassis_techpool)?,
+            trim_body_techpool: parse_measured("Trim Body Techpool", &raw.trim_body_techpool)?,
+            trim_aerodynamics_techpool: parse_measured("Trim Aerodynamics Techpool", &raw.trim_aerodynamics_techpool)?,
+            trim_interior_techpool: parse_measured("Trim Interior Techpool", &raw.trim_interior_techpool)?,
+            trim_fixture_techpool: parse_measured("Trim Fixture Techpool", &raw.trim_fixture_techpool)?,
+            trim_tyre_techpool: parse_measured("Trim Tyre Techpool", &raw.trim_tyre_techpool)?,
+            trim_suspension_techpool: parse_measured("Trim Suspension Techpool", &raw.trim_suspension_techpool)?,
+            trim_safety_techpool: parse_measured("Trim Safety Techpool", &raw.trim_safety_techpool)?,
+            trim_brake_techpool: parse_measured("Trim Brake Techpool", &raw.trim_brake_techpool)?,
+            trim_assist_techpool: parse_measured("Trim Assist Techpool", &raw.trim_assist_techpool)?,
+            trim_drivetrain_techpool: parse_measured("Trim Drivetrain Techpool", &raw.trim_drivetrain_techpool)?,
+
+        // Body
+            body_type: normalise(&raw.body_type),
+            doors: parse_count("Doors", &raw.doors)?,
+            body_name: normalise(&raw.body_name),
+            convertible_type: normalise(&raw.convertible_type),
+            full_1st_row_seats: parse_count("Full 1st Row Seats", &raw.full_1st_row_seats)?,
+            small_1st_row_seats: parse_count("Small 1st Row Seats", &raw.small_1st_row_seats)?,
+            full_2nd_row_seats: parse_count("Full 2nd Row Seats", &raw.full_2nd_row_seats)?,
+            small_2nd_row_seats: parse_count("Small 2nd Row Seats", &raw.small_2nd_row_seats)?,
+            full_3rd_row_seats: parse_count("Full 3rd Row Seats", &raw.full_3rd_row_seats)?,
+            small_3rd_row_seats: parse_count("Small 3rd Row Seats", &raw.small_3rd_row_seats)?,
+            passenger_volume: parse_measured("Passenger Volume", &raw.passenger_volume)?,
+            cargo_volume: parse_measured("Cargo Volume", &raw.cargo_volume)?,
+
+        // Aero and cooling
+            active_cooling: normalise(&raw.active_cooling),
+            cooling_amount: parse_measured("Cooling Amount", &raw.cooling_amount)?,
+            undertray: normalise(&raw.undertray),
+            active_aero: normalise(&raw.active_aero),
+            rear_wing_angle: parse_measured("Rear Wing Angle", &raw.rear_wing_angle)?,
+            front_wing_angle: parse_measured("Front Wing Angle", &raw.front_wing_angle)?,
+            front_downforce: parse_measured("Front Downforce", &raw.front_downforce)?,
+            rear_downforce: parse_measured("Rear Downforce", &raw.rear_downforce)?,
+
+        // Interior and assists
+            interior: normalise(&raw.interior),
+            entertainment: normalise(&raw.entertainment),
+            power_steering: normalise(&raw.power_steering),
+            assists: normalise(&raw.assists),
+            safety: normalise(&raw.safety),
+
+        // Suspension
+            springs: normalise(&raw.springs),
+            front_spring_stiffness: parse_measured("Front Spring Stiffness", &raw.front_spring_stiffness)?,
+            rear_spring_stiffness: parse_measured("Rear Spring Stiffness", &raw.rear_spring_stiffness)?,
+            dampers: normalise(&raw.dampers),
+            front_damper_stiffness: parse_measured("Front Damper Stiffness", &raw.front_damper_stiffness)?,
+            rear_damper_stiffness: parse_measured("Rear Damper Stiffness", &raw.rear_damper_stiffness)?,
+            sway_bars: normalise(&raw.sway_bars),
+            front_sway_bar_stiffness: parse_measured("Front Sway Bar Stiffness", &raw.front_sway_bar_stiffness)?,
+            rear_sway_bar_stiffness: parse_measured("Rear Sway Bar Stiffness", &raw.rear_sway_bar_stiffness)?,
+            ride_height: parse_measured("Ride Height", &raw.ride_height)?,
+            front_camber: parse_measured("Front Camber", &raw.front_camber)?,
+            rear_camber: parse_measured("Rear Camber", &raw.rear_camber)?,
+
+        // Brakes
+            front_brake_type: normalise(&raw.front_brake_type),
+            rear_brake_type: normalise(&raw.rear_brake_type),
+            front_caliper_pistons: parse_count("Front Caliper Pistons", &raw.front_caliper_pistons)?,
+            rear_caliper_pistons: parse_count("Rear Caliper Pistons", &raw.rear_caliper_pistons)?,
+            front_brake_diameter: parse_measured("Front Brake Diameter", &raw.front_brake_diameter)?,
+            rear_brake_diameter: parse_measured("Rear Brake Diameter", &raw.rear_brake_diameter)?,
+            front_brake_pad_type: parse_measured("Front Brake Pad Type", &raw.front_brake_pad_type)?,
+            rear_brake_pad_type: parse_measured("Rear Brake Pad Type", &raw.rear_brake_pad_type)?,
+            front_brake_force: parse_measured("Front Brake Force", &raw.front_brake_force)?,
+            rear_brake_force: parse_measured("Rear Brake Force", &raw.rear_brake_force)?,
+
+        // Weight
+            weight_optimization_tune: parse_measured("Weight Optimization Tune", &raw.weight_optimization_tune)?,
+            weight_distribution_tune: parse_measured("Weight Distribution Tune", &raw.weight_distribution_tune)?,
+            trim_weight: parse_measured("Trim Weight", &raw.trim_weight)?,
+            tow_weight: parse_measured("Tow Weight", &raw.tow_weight)?,
+            body_stiffness: parse_measured("Body Stiffness", &raw.body_stiffness)?,
+
+        // Economics
+            trim_engineering_time: parse_measured("Trim Engineering Time", &raw.trim_engineering_time)?,
+            trim_production_units: parse_measured("Trim Production Units", &raw.trim_production_units)?,
+            trim_price: parse_measured("Trim Price", &raw.trim_price)?,
+            trim_tooling_costs: parse_measured("Trim Tooling Costs", &raw.trim_tooling_costs)?,
+            trim_total_costs: parse_measured("Trim Total Costs", &raw.trim_total_costs)?,
+            trim_material_cost: parse_measured("Trim Material Cost", &raw.trim_material_cost)?,
+            trim_engineering_costs: parse_measured("Trim Engineering Costs", &raw.trim_engineering_costs)?,
+            engine_engineering_time: parse_measured("Engine Engineering Time", &raw.engine_engineering_time)?,
+            engine_production_units: parse_measured("Engine Production Units", &raw.engine_production_units)?,
+            engine_engineering_costs: parse_measured("Engine Engineering Costs", &raw.engine_engineering_costs)?,
+            engine_tooling_costs: parse_measured("Engine Tooling Costs", &raw.engine_tooling_costs)?,
+            engine_material_cost: parse_measured("Engine Material Cost", &raw.engine_material_cost)?,
+            engine_total_cost: parse_measured("Engine Total Cost", &raw.engine_total_cost)?,
+            engine_service_cost: parse_measured("Engine Service Cost", &raw.engine_service_cost)?,
+
+        // Performance
+            braking_distance: parse_measured("Braking Distance", &raw.braking_distance)?,
+            max_body_roll: parse_measured("Max Body Roll", &raw.max_body_roll)?,
+            cornering: parse_measured("Cornering", &raw.cornering)?,
+            top_speed_km_h: parse_measured("Top Speed (km/h)", &raw.top_speed_km_h)?,
+            top_speed_gear: parse_count("Top Speed Gear", &raw.top_speed_gear)?,
+            top_speed_engine_power: parse_measured("Top Speed Engine Power", &raw.top_speed_engine_power)?,
+            top_speed_engine_rpm: parse_measured("Top Speed Engine RPM", &raw.top_speed_engine_rpm)?,
+            time_60: parse_measured("60 Time", &raw.time_60)?,
+            time_80: parse_measured("80 Time", &raw.time_80)?,
+            time_100: parse_measured("100 Time", &raw.time_100)?,
+            time_200: parse_measured("200 Time", &raw.time_200)?,
+            time_120: parse_measured("120 Time", &raw.time_120)?,
+            time_80_to_120: parse_measured("80 To 120 Time", &raw.time_80_to_120)?,
+            kilometer_time: parse_measured("Kilometer Time", &raw.kilometer_time)?,
+            kilometer_speed_km_h: parse_measured("Kilometer Speed (km/h)", &raw.kilometer_speed_km_h)?,
+            quarter_mile_time: parse_measured("1/4 Mile Time", &raw.quarter_mile_time)?,
+            quarter_mile_speed_km_h: parse_measured("1/4 Mile Speed (km/h)", &raw.quarter_mile_speed_km_h)?,
         })
     }
 
+    /// Every quality slider of the car, model, trim and engine. Sliders missing
+    /// from the export are None.
+    pub fn qualities(&self) -> Vec<(&'static str, Option<f32>)> {
+        vec![
+            ("family_quality", self.family_quality),
+            ("variant_top_end_quality", self.variant_top_end_quality),
+            ("variant_bottom_end_quality", self.variant_bottom_end_quality),
+            ("variant_aspiration_quality", self.variant_aspiration_quality),
+            ("variant_fuel_system_quality", self.variant_fuel_system_quality),
+            ("variant_exhaust_quality", self.variant_exhaust_quality),
+            ("model_body_quality", Some(self.model_body_quality)),
+            ("model_chassis_quality", Some(self.model_chassis_quality)),
+            ("trim_body_quality", self.trim_body_quality),
+            ("trim_aerodynamics_quality", self.trim_aerodynamics_quality),
+            ("trim_interior_quality", self.trim_interior_quality),
+            ("trim_fixture_quality", self.trim_fixture_quality),
+            ("trim_tyre_quality", self.trim_tyre_quality),
+            ("trim_suspension_quality", self.trim_suspension_quality),
+            ("trim_safety_quality", self.trim_safety_quality),
+            ("trim_brake_quality", self.trim_brake_quality),
+            ("trim_assist_quality", self.trim_assist_quality),
+            ("trim_drivetrain_quality", self.trim_drivetrain_quality),
+        ]
+    }
+
+    /// Whether the engine has any forced induction, turbo or supercharger.
     pub fn has_turbo(&self) -> bool {
-        &self.aspiration.trim() != &"Naturally Aspirated"
+        !self.aspiration.trim().eq_ignore_ascii_case("Naturally Aspirated")
     }
 
     pub fn wheels_match(&self) -> bool {
@@ -168,23 +757,41 @@ impl Car {
 
 #[derive(Debug)]
 pub enum ImportError {
-    Unknown,
+    MissingCsv(String),
+    BadEncoding(String),
+    BadCsv(String),
+    NoData,
+    UnknownTrim(String),
+    MissingColumn(String),
+    BadNumber { column: String, value: String },
 }
 
 impl std::fmt::Display for ImportError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::result::Result<(), std::fmt::Error> {
-        write!(f, "{}", format!("{:?}", self))
+        match self {
+            Self::MissingCsv(path) => write!(f, "missing csv file {}", path),
+            Self::BadEncoding(e) => write!(f, "csv file has a bad encoding ({})", e),
+            Self::BadCsv(e) => write!(f, "csv file is malformed ({})", e),
+            Self::NoData => write!(f, "csv file has no car in it"),
+            Self::UnknownTrim(trim) => write!(f, "csv file has no trim called \"{}\"", trim),
+            Self::MissingColumn(column) => write!(f, "missing column \"{}\"", column),
+            Self::BadNumber { column, value } => write!(f, "column \"{}\" is not a number: \"{}\"", column, value),
+        }
     }
 }
 
 impl std::error::Error for ImportError {}
 
 #[allow(dead_code)]
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
 pub struct RawCar {
-    #[serde(rename(deserialize = "Exporter Version"))]
+    /// Problems with the columns of the export that did not stop it from being imported.
+    #[serde(skip)]
+    pub import_warnings: Vec<String>,
+
+    #[serde(default, rename(deserialize = "Exporter Version"))]
     pub exporter_version: String,
-    #[serde(rename(deserialize = "Game Version"))]
+    #[serde(default, rename(deserialize = "Game Version"))]
     pub game_version: String,
     #[serde(rename(deserialize = "Car Name"))]
     pub car_name: String,
@@ -214,139 +821,139 @@ pub struct RawCar {
     pub model_chassis_quality: String,
     #[serde(rename(deserialize = "Model Body Techpool"))]
     pub model_body_techpool: String,
-    #[serde(rename(deserialize = "Model Chassis Techpool"))]
+    #[serde(default, rename(deserialize = "Model Chassis Techpool"))]
     pub model_chassis_techpool: String,
-    #[serde(rename(deserialize = "Trim Name"))]
+    #[serde(default, rename(deserialize = "Trim Name"))]
     pub trim_name: String,
-    #[serde(rename(deserialize = "Trim Year"))]
+    #[serde(default, rename(deserialize = "Trim Year"))]
     pub trim_year: String,
-    #[serde(rename(deserialize = "Trim Body Quality"))]
+    #[serde(default, rename(deserialize = "Trim Body Quality"))]
     pub trim_body_quality: String,
-    #[serde(rename(deserialize = "Trim Aerodynamics Quality"))]
+    #[serde(default, rename(deserialize = "Trim Aerodynamics Quality"))]
     pub trim_aerodynamics_quality: String,
-    #[serde(rename(deserialize = "Trim Interior Quality"))]
+    #[serde(default, rename(deserialize = "Trim Interior Quality"))]
     pub trim_interior_quality: String,
-    #[serde(rename(deserialize = "Trim Fixture Quality"))]
+    #[serde(default, rename(deserialize = "Trim Fixture Quality"))]
     pub trim_fixture_quality: String,
-    #[serde(rename(deserialize = "Trim Tyre Quality"))]
+    #[serde(default, rename(deserialize = "Trim Tyre Quality"))]
     pub trim_tyre_quality: String,
-    #[serde(rename(deserialize = "Trim Suspension Quality"))]
+    #[serde(default, rename(deserialize = "Trim Suspension Quality"))]
     pub trim_suspension_quality: String,
-    #[serde(rename(deserialize = "Trim Safety Quality"))]
+    #[serde(default, rename(deserialize = "Trim Safety Quality"))]
     pub trim_safety_quality: String,
-    #[serde(rename(deserialize = "Trim Brake Quality"))]
+    #[serde(default, rename(deserialize = "Trim Brake Quality"))]
     pub trim_brake_quality: String,
-    #[serde(rename(deserialize = "Trim Assist Quality"))]
+    #[serde(default, rename(deserialize = "Trim Assist Quality"))]
     pub trim_assist_quality: String,
-    #[serde(rename(deserialize = "Trim Drivetrain Quality"))]
+    #[serde(default, rename(deserialize = "Trim Drivetrain Quality"))]
     pub trim_drivetrain_quality: String,
-    #[serde(rename(deserialize = "Trim Chassis Techpool"))]
+    #[serde(default, rename(deserialize = "Trim Chassis Techpool"))]
     pub trim_chassis_techpool: String,
-    #[serde(rename(deserialize = "Trim Body Techpool"))]
+    #[serde(default, rename(deserialize = "Trim Body Techpool"))]
     pub trim_body_techpool: String,
-    #[serde(rename(deserialize = "Trim Aerodynamics Techpool"))]
+    #[serde(default, rename(deserialize = "Trim Aerodynamics Techpool"))]
     pub trim_aerodynamics_techpool: String,
-    #[serde(rename(deserialize = "Trim Interior Techpool"))]
+    #[serde(default, rename(deserialize = "Trim Interior Techpool"))]
     pub trim_interior_techpool: String,
-    #[serde(rename(deserialize = "Trim Fixture Techpool"))]
+    #[serde(default, rename(deserialize = "Trim Fixture Techpool"))]
     pub trim_fixture_techpool: String,
-    #[serde(rename(deserialize = "Trim Tyre Techpool"))]
+    #[serde(default, rename(deserialize = "Trim Tyre Techpool"))]
     pub trim_tyre_techpool: String,
-    #[serde(rename(deserialize = "Trim Suspension Techpool"))]
+    #[serde(default, rename(deserialize = "Trim Suspension Techpool"))]
     pub trim_suspension_techpool: String,
-    #[serde(rename(deserialize = "Trim Safety Techpool"))]
+    #[serde(default, rename(deserialize = "Trim Safety Techpool"))]
     pub trim_safety_techpool: String,
-    #[serde(rename(deserialize = "Trim Brake Techpool"))]
+    #[serde(default, rename(deserialize = "Trim Brake Techpool"))]
     pub trim_brake_techpool: String,
-    #[serde(rename(deserialize = "Trim Assist Techpool"))]
+    #[serde(default, rename(deserialize = "Trim Assist Techpool"))]
     pub trim_assist_techpool: String,
-    #[serde(rename(deserialize = "Trim Drivetrain Techpool"))]
+    #[serde(default, rename(deserialize = "Trim Drivetrain Techpool"))]
     pub trim_drivetrain_techpool: String,
-    #[serde(rename(deserialize = "Body Type"))]
+    #[serde(default, rename(deserialize = "Body Type"))]
     pub body_type: String,
-    #[serde(rename(deserialize = "Doors"))]
+    #[serde(default, rename(deserialize = "Doors"))]
     pub doors: String,
-    #[serde(rename(deserialize = "Body Name"))]
+    #[serde(default, rename(deserialize = "Body Name"))]
     pub body_name: String,
-    #[serde(rename(deserialize = "Convertible Type"))]
+    #[serde(default, rename(deserialize = "Convertible Type"))]
     pub convertible_type: String,
-    #[serde(rename(deserialize = "Full 1st Row Seats"))]
+    #[serde(default, rename(deserialize = "Full 1st Row Seats"))]
     pub full_1st_row_seats: String,
-    #[serde(rename(deserialize = "Small 1st Row Seats"))]
+    #[serde(default, rename(deserialize = "Small 1st Row Seats"))]
     pub small_1st_row_seats: String,
-    #[serde(rename(deserialize = "Full 2nd Row Seats"))]
+    #[serde(default, rename(deserialize = "Full 2nd Row Seats"))]
     pub full_2nd_row_seats: String,
-    #[serde(rename(deserialize = "Small 2nd Row Seats"))]
+    #[serde(default, rename(deserialize = "Small 2nd Row Seats"))]
     pub small_2nd_row_seats: String,
-    #[serde(rename(deserialize = "Full 3rd Row Seats"))]
+    #[serde(default, rename(deserialize = "Full 3rd Row Seats"))]
     pub full_3rd_row_seats: String,
-    #[serde(rename(deserialize = "Small 3rd Row Seats"))]
+    #[serde(default, rename(deserialize = "Small 3rd Row Seats"))]
     pub small_3rd_row_seats: String,
     #[serde(rename(deserialize = "Drive Type"))]
     pub drive_type: String,
-    #[serde(rename(deserialize = "Gearbox Type"))]
+    #[serde(default, rename(deserialize = "Gearbox Type"))]
     pub gearbox_type: String,
-    #[serde(rename(deserialize = "Gear Count"))]
+    #[serde(default, rename(deserialize = "Gear Count"))]
     pub gear_count: String,
-    #[serde(rename(deserialize = "Gear Spacing"))]
+    #[serde(default, rename(deserialize = "Gear Spacing"))]
     pub gear_spacing: String,
-    #[serde(rename(deserialize = "Power To Front"))]
+    #[serde(default, rename(deserialize = "Power To Front"))]
     pub power_to_front: String,
-    #[serde(rename(deserialize = "Differential Type"))]
+    #[serde(default, rename(deserialize = "Differential Type"))]
     pub differential_type: String,
-    #[serde(rename(deserialize = "Final Drive"))]
+    #[serde(default, rename(deserialize = "Final Drive"))]
     pub final_drive: String,
-    #[serde(rename(deserialize = "Speed Limiter"))]
+    #[serde(default, rename(deserialize = "Speed Limiter"))]
     pub speed_limiter: String,
-    #[serde(rename(deserialize = "Active Cooling"))]
+    #[serde(default, rename(deserialize = "Active Cooling"))]
     pub active_cooling: String,
-    #[serde(rename(deserialize = "Cooling Amount"))]
+    #[serde(default, rename(deserialize = "Cooling Amount"))]
     pub cooling_amount: String,
-    #[serde(rename(deserialize = "Undertray"))]
+    #[serde(default, rename(deserialize = "Undertray"))]
     pub undertray: String,
-    #[serde(rename(deserialize = "Active Aero"))]
+    #[serde(default, rename(deserialize = "Active Aero"))]
     pub active_aero: String,
-    #[serde(rename(deserialize = "Rear Wing Angle"))]
+    #[serde(default, rename(deserialize = "Rear Wing Angle"))]
     pub rear_wing_angle: String,
-    #[serde(rename(deserialize = "Front Wing Angle"))]
+    #[serde(default, rename(deserialize = "Front Wing Angle"))]
     pub front_wing_angle: String,
-    #[serde(rename(deserialize = "Interior"))]
+    #[serde(default, rename(deserialize = "Interior"))]
     pub interior: String,
-    #[serde(rename(deserialize = "Entertainment"))]
+    #[serde(default, rename(deserialize = "Entertainment"))]
     pub entertainment: String,
-    #[serde(rename(deserialize = "Power Steering"))]
+    #[serde(default, rename(deserialize = "Power Steering"))]
     pub power_steering: String,
-    #[serde(rename(deserialize = "Assists"))]
+    #[serde(default, rename(deserialize = "Assists"))]
     pub assists: String,
-    #[serde(rename(deserialize = "Safety"))]
+    #[serde(default, rename(deserialize = "Safety"))]
     pub safety: String,
-    #[serde(rename(deserialize = "Springs"))]
+    #[serde(default, rename(deserialize = "Springs"))]
     pub springs: String,
-    #[serde(rename(deserialize = "Front Spring Stiffness"))]
+    #[serde(default, rename(deserialize = "Front Spring Stiffness"))]
     pub front_spring_stiffness: String,
-    #[serde(rename(deserialize = "Rear Spring Stiffness"))]
+    #[serde(default, rename(deserialize = "Rear Spring Stiffness"))]
     pub rear_spring_stiffness: String,
-    #[serde(rename(deserialize = "Dampers"))]
+    #[serde(default, rename(deserialize = "Dampers"))]
     pub dampers: String,
-    #[serde(rename(deserialize = "Front Damper Stiffness"))]
+    #[serde(default, rename(deserialize = "Front Damper Stiffness"))]
     pub front_damper_stiffness: String,
-    #[serde(rename(deserialize = "Rear Damper Stiffness"))]
+    #[serde(default, rename(deserialize = "Rear Damper Stiffness"))]
     pub rear_damper_stiffness: String,
-    #[serde(rename(deserialize = "Sway Bars"))]
+    #[serde(default, rename(deserialize = "Sway Bars"))]
     pub sway_bars: String,
-    #[serde(rename(deserialize = "Front Sway Bar Stiffness"))]
+    #[serde(default, rename(deserialize = "Front Sway Bar Stiffness"))]
     pub front_sway_bar_stiffness: String,
-    #[serde(rename(deserialize = "Rear Sway Bar Stiffness"))]
+    #[serde(default, rename(deserialize = "Rear Sway Bar Stiffness"))]
     pub rear_sway_bar_stiffness: String,
-    #[serde(rename(deserialize = "Ride Height"))]
+    #[serde(default, rename(deserialize = "Ride Height"))]
     pub ride_height: String,
-    #[serde(rename(deserialize = "Front Camber"))]
+    #[serde(default, rename(deserialize = "Front Camber"))]
     pub front_camber: String,
-    #[serde(rename(deserialize = "Rear Camber"))]
+    #[serde(default, rename(deserialize = "Rear Camber"))]
     pub rear_camber: String,
     #[serde(rename(deserialize = "Rim Material"))]
     pub rim_material: String,
-    #[serde(rename(deserialize = "Tyre Type"))]
+    #[serde(default, rename(deserialize = "Tyre Type"))]
     pub tyre_type: String,
     #[serde(rename(deserialize = "Tyre Compound"))]
     pub tyre_compound: String,
@@ -354,9 +961,9 @@ pub struct RawCar {
     pub front_rim_size: String,
     #[serde(rename(deserialize = "Rear Rim Size"))]
     pub rear_rim_size: String,
-    #[serde(rename(deserialize = "Front Wheel Offset"))]
+    #[serde(default, rename(deserialize = "Front Wheel Offset"))]
     pub front_wheel_offset: String,
-    #[serde(rename(deserialize = "Rear Wheel Offset"))]
+    #[serde(default, rename(deserialize = "Rear Wheel Offset"))]
     pub rear_wheel_offset: String,
     #[serde(rename(deserialize = "Front Tyre Profile"))]
     pub front_tyre_profile: String,
@@ -370,49 +977,49 @@ pub struct RawCar {
     pub front_wheel_diameter: String,
     #[serde(rename(deserialize = "Rear Wheel Diameter"))]
     pub rear_wheel_diameter: String,
-    #[serde(rename(deserialize = "Front Wheel Diameter 2"))]
+    #[serde(default, rename(deserialize = "Front Wheel Diameter 2"))]
     pub front_wheel_diameter_2: String,
-    #[serde(rename(deserialize = "Rear Wheel Diameter 2"))]
+    #[serde(default, rename(deserialize = "Rear Wheel Diameter 2"))]
     pub rear_wheel_diameter_2: String,
-    #[serde(rename(deserialize = "Front Brake Type"))]
+    #[serde(default, rename(deserialize = "Front Brake Type"))]
     pub front_brake_type: String,
-    #[serde(rename(deserialize = "Rear Brake Type"))]
+    #[serde(default, rename(deserialize = "Rear Brake Type"))]
     pub rear_brake_type: String,
-    #[serde(rename(deserialize = "Front Caliper Pistons"))]
+    #[serde(default, rename(deserialize = "Front Caliper Pistons"))]
     pub front_caliper_pistons: String,
-    #[serde(rename(deserialize = "Rear Caliper Pistons"))]
+    #[serde(default, rename(deserialize = "Rear Caliper Pistons"))]
     pub rear_caliper_pistons: String,
-    #[serde(rename(deserialize = "Front Brake Diameter"))]
+    #[serde(default, rename(deserialize = "Front Brake Diameter"))]
     pub front_brake_diameter: String,
-    #[serde(rename(deserialize = "Rear Brake Diameter"))]
+    #[serde(default, rename(deserialize = "Rear Brake Diameter"))]
     pub rear_brake_diameter: String,
-    #[serde(rename(deserialize = "Front Brake Pad Type"))]
+    #[serde(default, rename(deserialize = "Front Brake Pad Type"))]
     pub front_brake_pad_type: String,
-    #[serde(rename(deserialize = "Rear Brake Pad Type"))]
+    #[serde(default, rename(deserialize = "Rear Brake Pad Type"))]
     pub rear_brake_pad_type: String,
-    #[serde(rename(deserialize = "Front Brake Force"))]
+    #[serde(default, rename(deserialize = "Front Brake Force"))]
     pub front_brake_force: String,
-    #[serde(rename(deserialize = "Rear Brake Force"))]
+    #[serde(default, rename(deserialize = "Rear Brake Force"))]
     pub rear_brake_force: String,
-    #[serde(rename(deserialize = "Weight Optimization Tune"))]
+    #[serde(default, rename(deserialize = "Weight Optimization Tune"))]
     pub weight_optimization_tune: String,
-    #[serde(rename(deserialize = "Weight Distribution Tune"))]
+    #[serde(default, rename(deserialize = "Weight Distribution Tune"))]
     pub weight_distribution_tune: String,
-    #[serde(rename(deserialize = "Trim Weight"))]
+    #[serde(default, rename(deserialize = "Trim Weight"))]
     pub trim_weight: String,
-    #[serde(rename(deserialize = "Tow Weight"))]
+    #[serde(default, rename(deserialize = "Tow Weight"))]
     pub tow_weight: String,
-    #[serde(rename(deserialize = "Body Stiffness"))]
+    #[serde(default, rename(deserialize = "Body Stiffness"))]
     pub body_stiffness: String,
     #[serde(rename(deserialize = "Sportiness Rating"))]
     pub sportiness_rating: String,
     #[serde(rename(deserialize = "Drivability Rating"))]
     pub drivability_rating: String,
-    #[serde(rename(deserialize = "Utility Rating"))]
+    #[serde(default, rename(deserialize = "Utility Rating"))]
     pub utility_rating: String,
-    #[serde(rename(deserialize = "Environmental Resistance"))]
+    #[serde(default, rename(deserialize = "Environmental Resistance"))]
     pub environmental_resistance: String,
-    #[serde(rename(deserialize = "Trim Emissions"))]
+    #[serde(default, rename(deserialize = "Trim Emissions"))]
     pub trim_emissions: String,
     #[serde(rename(deserialize = "Offroad Rating"))]
     pub offroad_rating: String,
@@ -428,79 +1035,79 @@ pub struct RawCar {
     pub safety_rating: String,
     #[serde(rename(deserialize = "Comfort Rating"))]
     pub comfort_rating: String,
-    #[serde(rename(deserialize = "Passenger Volume"))]
+    #[serde(default, rename(deserialize = "Passenger Volume"))]
     pub passenger_volume: String,
-    #[serde(rename(deserialize = "Cargo Volume"))]
+    #[serde(default, rename(deserialize = "Cargo Volume"))]
     pub cargo_volume: String,
-    #[serde(rename(deserialize = "Trim Engineering Time"))]
+    #[serde(default, rename(deserialize = "Trim Engineering Time"))]
     pub trim_engineering_time: String,
-    #[serde(rename(deserialize = "Trim Production Units"))]
+    #[serde(default, rename(deserialize = "Trim Production Units"))]
     pub trim_production_units: String,
-    #[serde(rename(deserialize = "Trim Price"))]
+    #[serde(default, rename(deserialize = "Trim Price"))]
     pub trim_price: String,
-    #[serde(rename(deserialize = "Trim Tooling Costs"))]
+    #[serde(default, rename(deserialize = "Trim Tooling Costs"))]
     pub trim_tooling_costs: String,
     #[serde(rename(deserialize = "Trim Service Costs"))]
     pub trim_service_costs: String,
-    #[serde(rename(deserialize = "Trim Total Costs"))]
+    #[serde(default, rename(deserialize = "Trim Total Costs"))]
     pub trim_total_costs: String,
     #[serde(rename(deserialize = "Trim Cost"))]
     pub trim_cost: String,
-    #[serde(rename(deserialize = "Trim Material Cost"))]
+    #[serde(default, rename(deserialize = "Trim Material Cost"))]
     pub trim_material_cost: String,
-    #[serde(rename(deserialize = "Trim Engineering Costs"))]
+    #[serde(default, rename(deserialize = "Trim Engineering Costs"))]
     pub trim_engineering_costs: String,
-    #[serde(rename(deserialize = "Braking Distance"))]
+    #[serde(default, rename(deserialize = "Braking Distance"))]
     pub braking_distance: String,
-    #[serde(rename(deserialize = "Max Body Roll"))]
+    #[serde(default, rename(deserialize = "Max Body Roll"))]
     pub max_body_roll: String,
-    #[serde(rename(deserialize = "Cornering"))]
+    #[serde(default, rename(deserialize = "Cornering"))]
     pub cornering: String,
-    #[serde(rename(deserialize = "Front Downforce"))]
+    #[serde(default, rename(deserialize = "Front Downforce"))]
     pub front_downforce: String,
-    #[serde(rename(deserialize = "Rear Downforce"))]
+    #[serde(default, rename(deserialize = "Rear Downforce"))]
     pub rear_downforce: String,
-    #[serde(rename(deserialize = "Tyre Speed Index"))]
+    #[serde(default, rename(deserialize = "Tyre Speed Index"))]
     pub tyre_speed_index: String,
-    #[serde(rename(deserialize = "Tyre Speed Rating (km/h)"))]
+    #[serde(default, rename(deserialize = "Tyre Speed Rating (km/h)"))]
     pub tyre_speed_rating_km_h: String,
-    #[serde(rename(deserialize = "Top Speed (km/h)"))]
+    #[serde(default, rename(deserialize = "Top Speed (km/h)"))]
     pub top_speed_km_h: String,
-    #[serde(rename(deserialize = "Top Speed Gear"))]
+    #[serde(default, rename(deserialize = "Top Speed Gear"))]
     pub top_speed_gear: String,
-    #[serde(rename(deserialize = "Top Speed Engine Power"))]
+    #[serde(default, rename(deserialize = "Top Speed Engine Power"))]
     pub top_speed_engine_power: String,
-    #[serde(rename(deserialize = "Top Speed Engine RPM"))]
+    #[serde(default, rename(deserialize = "Top Speed Engine RPM"))]
     pub top_speed_engine_rpm: String,
-    #[serde(rename(deserialize = "60 Time"))]
+    #[serde(default, rename(deserialize = "60 Time"))]
     pub time_60: String,
-    #[serde(rename(deserialize = "80 Time"))]
+    #[serde(default, rename(deserialize = "80 Time"))]
     pub time_80: String,
-    #[serde(rename(deserialize = "100 Time"))]
+    #[serde(default, rename(deserialize = "100 Time"))]
     pub time_100: String,
-    #[serde(rename(deserialize = "200 Time"))]
+    #[serde(default, rename(deserialize = "200 Time"))]
     pub time_200: String,
-    #[serde(rename(deserialize = "120 Time"))]
+    #[serde(default, rename(deserialize = "120 Time"))]
     pub time_120: String,
-    #[serde(rename(deserialize = "80 To 120 Time"))]
+    #[serde(default, rename(deserialize = "80 To 120 Time"))]
     pub time_80_to_120: String,
-    #[serde(rename(deserialize = "Kilometer Time"))]
+    #[serde(default, rename(deserialize = "Kilometer Time"))]
     pub kilometer_time: String,
-    #[serde(rename(deserialize = "Kilometer Speed (km/h)"))]
+    #[serde(default, rename(deserialize = "Kilometer Speed (km/h)"))]
     pub kilometer_speed_km_h: String,
-    #[serde(rename(deserialize = "1/4 Mile Time"))]
+    #[serde(default, rename(deserialize = "1/4 Mile Time"))]
     pub quarter_mile_time: String,
-    #[serde(rename(deserialize = "1/4 Mile Speed (km/h)"))]
+    #[serde(default, rename(deserialize = "1/4 Mile Speed (km/h)"))]
     pub quarter_mile_speed_km_h: String,
-    #[serde(rename(deserialize = "Engine Family Name"))]
+    #[serde(default, rename(deserialize = "Engine Family Name"))]
     pub engine_family_name: String,
-    #[serde(rename(deserialize = "Engine Family Year"))]
+    #[serde(default, rename(deserialize = "Engine Family Year"))]
     pub engine_family_year: String,
-    #[serde(rename(deserialize = "Family Quality"))]
+    #[serde(default, rename(deserialize = "Family Quality"))]
     pub family_quality: String,
-    #[serde(rename(deserialize = "Family Techpool"))]
+    #[serde(default, rename(deserialize = "Family Techpool"))]
     pub family_techpool: String,
-    #[serde(rename(deserialize = "Family Top End Techpool"))]
+    #[serde(default, rename(deserialize = "Family Top End Techpool"))]
     pub family_top_end_techpool: String,
     #[serde(rename(deserialize = "Family Bore"))]
     pub family_bore: String,
@@ -508,101 +1115,101 @@ pub struct RawCar {
     pub family_stroke: String,
     #[serde(rename(deserialize = "Family Displacement"))]
     pub family_displacement: String,
-    #[serde(rename(deserialize = "Block Type"))]
+    #[serde(default, rename(deserialize = "Block Type"))]
     pub block_type: String,
     #[serde(rename(deserialize = "Cylinder Count"))]
     pub cylinder_count: String,
-    #[serde(rename(deserialize = "Block Material"))]
+    #[serde(default, rename(deserialize = "Block Material"))]
     pub block_material: String,
-    #[serde(rename(deserialize = "Head Material"))]
+    #[serde(default, rename(deserialize = "Head Material"))]
     pub head_material: String,
-    #[serde(rename(deserialize = "Head Type"))]
+    #[serde(default, rename(deserialize = "Head Type"))]
     pub head_type: String,
     #[serde(rename(deserialize = "Intake Valves"))]
     pub intake_valves: String,
     #[serde(rename(deserialize = "Exhaust Valves"))]
     pub exhaust_valves: String,
-    #[serde(rename(deserialize = "Variant Name"))]
+    #[serde(default, rename(deserialize = "Variant Name"))]
     pub variant_name: String,
     #[serde(rename(deserialize = "Variant Year"))]
     pub variant_year: String,
-    #[serde(rename(deserialize = "Variant Top End Quality"))]
+    #[serde(default, rename(deserialize = "Variant Top End Quality"))]
     pub variant_top_end_quality: String,
-    #[serde(rename(deserialize = "Variant Bottom End Quality"))]
+    #[serde(default, rename(deserialize = "Variant Bottom End Quality"))]
     pub variant_bottom_end_quality: String,
-    #[serde(rename(deserialize = "Variant Aspiration Quality"))]
+    #[serde(default, rename(deserialize = "Variant Aspiration Quality"))]
     pub variant_aspiration_quality: String,
-    #[serde(rename(deserialize = "Variant Fuel System Quality"))]
+    #[serde(default, rename(deserialize = "Variant Fuel System Quality"))]
     pub variant_fuel_system_quality: String,
-    #[serde(rename(deserialize = "Variant Exhaust Quality"))]
+    #[serde(default, rename(deserialize = "Variant Exhaust Quality"))]
     pub variant_exhaust_quality: String,
-    #[serde(rename(deserialize = "Variant Top End Techpool"))]
+    #[serde(default, rename(deserialize = "Variant Top End Techpool"))]
     pub variant_top_end_techpool: String,
-    #[serde(rename(deserialize = "Variant Family Techpool"))]
+    #[serde(default, rename(deserialize = "Variant Family Techpool"))]
     pub variant_family_techpool: String,
-    #[serde(rename(deserialize = "Variant Bottom End Techpool"))]
+    #[serde(default, rename(deserialize = "Variant Bottom End Techpool"))]
     pub variant_bottom_end_techpool: String,
-    #[serde(rename(deserialize = "Variant Aspiration Techpool"))]
+    #[serde(default, rename(deserialize = "Variant Aspiration Techpool"))]
     pub variant_aspiration_techpool: String,
-    #[serde(rename(deserialize = "Variant Fuel System Techpool"))]
+    #[serde(default, rename(deserialize = "Variant Fuel System Techpool"))]
     pub variant_fuel_system_techpool: String,
-    #[serde(rename(deserialize = "Variant Exhaust Techpool"))]
+    #[serde(default, rename(deserialize = "Variant Exhaust Techpool"))]
     pub variant_exhaust_techpool: String,
-    #[serde(rename(deserialize = "Variant Bore"))]
+    #[serde(default, rename(deserialize = "Variant Bore"))]
     pub variant_bore: String,
-    #[serde(rename(deserialize = "Variant Stroke"))]
+    #[serde(default, rename(deserialize = "Variant Stroke"))]
     pub variant_stroke: String,
-    #[serde(rename(deserialize = "Variant Displacement"))]
+    #[serde(default, rename(deserialize = "Variant Displacement"))]
     pub variant_displacement: String,
-    #[serde(rename(deserialize = "Balance Shaft"))]
+    #[serde(default, rename(deserialize = "Balance Shaft"))]
     pub balance_shaft: String,
-    #[serde(rename(deserialize = "Crankshaft"))]
+    #[serde(default, rename(deserialize = "Crankshaft"))]
     pub crankshaft: String,
-    #[serde(rename(deserialize = "Conrods"))]
+    #[serde(default, rename(deserialize = "Conrods"))]
     pub conrods: String,
-    #[serde(rename(deserialize = "Pistons"))]
+    #[serde(default, rename(deserialize = "Pistons"))]
     pub pistons: String,
     #[serde(rename(deserialize = "Compression"))]
     pub compression: String,
-    #[serde(rename(deserialize = "Cam Profile"))]
+    #[serde(default, rename(deserialize = "Cam Profile"))]
     pub cam_profile: String,
-    #[serde(rename(deserialize = "Valve Spring Stiffness"))]
+    #[serde(default, rename(deserialize = "Valve Spring Stiffness"))]
     pub valve_spring_stiffness: String,
-    #[serde(rename(deserialize = "VVT"))]
+    #[serde(default, rename(deserialize = "VVT"))]
     pub vvt: String,
-    #[serde(rename(deserialize = "VVL"))]
+    #[serde(default, rename(deserialize = "VVL"))]
     pub vvl: String,
     #[serde(rename(deserialize = "Aspiration"))]
     pub aspiration: String,
-    #[serde(rename(deserialize = "Intercooler Size"))]
+    #[serde(default, rename(deserialize = "Intercooler Size"))]
     pub intercooler_size: String,
-    #[serde(rename(deserialize = "Aspiration Item 1"))]
+    #[serde(default, rename(deserialize = "Aspiration Item 1"))]
     pub aspiration_item_1: String,
-    #[serde(rename(deserialize = "Aspiration Sub Item 1"))]
+    #[serde(default, rename(deserialize = "Aspiration Sub Item 1"))]
     pub aspiration_sub_item_1: String,
-    #[serde(rename(deserialize = "Aspiration Item 2"))]
+    #[serde(default, rename(deserialize = "Aspiration Item 2"))]
     pub aspiration_item_2: String,
-    #[serde(rename(deserialize = "Aspiration Sub Item 2"))]
+    #[serde(default, rename(deserialize = "Aspiration Sub Item 2"))]
     pub aspiration_sub_item_2: String,
-    #[serde(rename(deserialize = "Boost Control"))]
+    #[serde(default, rename(deserialize = "Boost Control"))]
     pub boost_control: String,
-    #[serde(rename(deserialize = "Compressor Size 1"))]
+    #[serde(default, rename(deserialize = "Compressor Size 1"))]
     pub compressor_size_1: String,
-    #[serde(rename(deserialize = "Compressor Size 2"))]
+    #[serde(default, rename(deserialize = "Compressor Size 2"))]
     pub compressor_size_2: String,
-    #[serde(rename(deserialize = "Turbine Size 1"))]
+    #[serde(default, rename(deserialize = "Turbine Size 1"))]
     pub turbine_size_1: String,
-    #[serde(rename(deserialize = "Turbine Size 2"))]
+    #[serde(default, rename(deserialize = "Turbine Size 2"))]
     pub turbine_size_2: String,
-    #[serde(rename(deserialize = "Aspiration Tune 1"))]
+    #[serde(default, rename(deserialize = "Aspiration Tune 1"))]
     pub aspiration_tune_1: String,
-    #[serde(rename(deserialize = "Aspiration Tune 2"))]
+    #[serde(default, rename(deserialize = "Aspiration Tune 2"))]
     pub aspiration_tune_2: String,
-    #[serde(rename(deserialize = "Fuel System Type"))]
+    #[serde(default, rename(deserialize = "Fuel System Type"))]
     pub fuel_system_type: String,
-    #[serde(rename(deserialize = "Fuel System"))]
+    #[serde(default, rename(deserialize = "Fuel System"))]
     pub fuel_system: String,
-    #[serde(rename(deserialize = "Throttle Configuration"))]
+    #[serde(default, rename(deserialize = "Throttle Configuration"))]
     pub throttle_configuration: String,
     #[serde(rename(deserialize = "Intake"))]
     pub intake: String,
@@ -610,107 +1217,137 @@ pub struct RawCar {
     pub leaded_fuel: String,
     #[serde(rename(deserialize = "Fuel Octane"))]
     pub fuel_octane: String,
-    #[serde(rename(deserialize = "Octane Offset"))]
+    #[serde(default, rename(deserialize = "Octane Offset"))]
     pub octane_offset: String,
-    #[serde(rename(deserialize = "AFR"))]
+    #[serde(default, rename(deserialize = "AFR"))]
     pub afr: String,
-    #[serde(rename(deserialize = "Ignition Timing"))]
+    #[serde(default, rename(deserialize = "Ignition Timing"))]
     pub ignition_timing: String,
-    #[serde(rename(deserialize = "RPM Limit"))]
+    #[serde(default, rename(deserialize = "RPM Limit"))]
     pub rpm_limit: String,
     #[serde(rename(deserialize = "Headers"))]
     pub headers: String,
-    #[serde(rename(deserialize = "Exhaust Count"))]
+    #[serde(default, rename(deserialize = "Exhaust Count"))]
     pub exhaust_count: String,
-    #[serde(rename(deserialize = "Exhaust Diameter"))]
+    #[serde(default, rename(deserialize = "Exhaust Diameter"))]
     pub exhaust_diameter: String,
-    #[serde(rename(deserialize = "Exhaust Bypass Valves"))]
+    #[serde(default, rename(deserialize = "Exhaust Bypass Valves"))]
     pub exhaust_bypass_valves: String,
     #[serde(rename(deserialize = "Catalytic Converter"))]
     pub catalytic_converter: String,
-    #[serde(rename(deserialize = "Muffler 1"))]
+    #[serde(default, rename(deserialize = "Muffler 1"))]
     pub muffler_1: String,
-    #[serde(rename(deserialize = "Muffler 2"))]
+    #[serde(default, rename(deserialize = "Muffler 2"))]
     pub muffler_2: String,
-    #[serde(rename(deserialize = "Peak Power"))]
+    #[serde(default, rename(deserialize = "Peak Power"))]
     pub peak_power: String,
-    #[serde(rename(deserialize = "Peak Power RPM"))]
+    #[serde(default, rename(deserialize = "Peak Power RPM"))]
     pub peak_power_rpm: String,
-    #[serde(rename(deserialize = "Peak Torque"))]
+    #[serde(default, rename(deserialize = "Peak Torque"))]
     pub peak_torque: String,
-    #[serde(rename(deserialize = "Peak Torque RPM"))]
+    #[serde(default, rename(deserialize = "Peak Torque RPM"))]
     pub peak_torque_rpm: String,
-    #[serde(rename(deserialize = "Peak Boost"))]
+    #[serde(default, rename(deserialize = "Peak Boost"))]
     pub peak_boost: String,
-    #[serde(rename(deserialize = "Peak Boost RPM"))]
+    #[serde(default, rename(deserialize = "Peak Boost RPM"))]
     pub peak_boost_rpm: String,
-    #[serde(rename(deserialize = "Idle RPM"))]
+    #[serde(default, rename(deserialize = "Idle RPM"))]
     pub idle_rpm: String,
     #[serde(rename(deserialize = "Max RPM"))]
     pub max_rpm: String,
-    #[serde(rename(deserialize = "Min Economy RPM"))]
+    #[serde(default, rename(deserialize = "Min Economy RPM"))]
     pub min_economy_rpm: String,
-    #[serde(rename(deserialize = "Engine Weight"))]
+    #[serde(default, rename(deserialize = "Engine Weight"))]
     pub engine_weight: String,
-    #[serde(rename(deserialize = "Flywheel Weight"))]
+    #[serde(default, rename(deserialize = "Flywheel Weight"))]
     pub flywheel_weight: String,
-    #[serde(rename(deserialize = "Required Cooling"))]
+    #[serde(default, rename(deserialize = "Required Cooling"))]
     pub required_cooling: String,
-    #[serde(rename(deserialize = "Engine Performance Index"))]
+    #[serde(default, rename(deserialize = "Engine Performance Index"))]
     pub engine_performance_index: String,
-    #[serde(rename(deserialize = "Engine Responsiveness"))]
+    #[serde(default, rename(deserialize = "Engine Responsiveness"))]
     pub engine_responsiveness: String,
-    #[serde(rename(deserialize = "Engine Smoothness"))]
+    #[serde(default, rename(deserialize = "Engine Smoothness"))]
     pub engine_smoothness: String,
-    #[serde(rename(deserialize = "Engine Emissions"))]
+    #[serde(default, rename(deserialize = "Engine Emissions"))]
     pub engine_emissions: String,
-    #[serde(rename(deserialize = "Engine Efficiency"))]
+    #[serde(default, rename(deserialize = "Engine Efficiency"))]
     pub engine_efficiency: String,
-    #[serde(rename(deserialize = "Engine Noise"))]
+    #[serde(default, rename(deserialize = "Engine Noise"))]
     pub engine_noise: String,
-    #[serde(rename(deserialize = "Intake Noise"))]
+    #[serde(default, rename(deserialize = "Intake Noise"))]
     pub intake_noise: String,
-    #[serde(rename(deserialize = "Exhaust Noise"))]
+    #[serde(default, rename(deserialize = "Exhaust Noise"))]
     pub exhaust_noise: String,
-    #[serde(rename(deserialize = "Engine RON Rating"))]
+    #[serde(default, rename(deserialize = "Engine RON Rating"))]
     pub engine_ron_rating: String,
-    #[serde(rename(deserialize = "Engine Engineering Time"))]
+    #[serde(default, rename(deserialize = "Engine Engineering Time"))]
     pub engine_engineering_time: String,
-    #[serde(rename(deserialize = "Engine Production Units"))]
+    #[serde(default, rename(deserialize = "Engine Production Units"))]
     pub engine_production_units: String,
-    #[serde(rename(deserialize = "Engine Engineering Costs"))]
+    #[serde(default, rename(deserialize = "Engine Engineering Costs"))]
     pub engine_engineering_costs: String,
-    #[serde(rename(deserialize = "Engine Tooling Costs"))]
+    #[serde(default, rename(deserialize = "Engine Tooling Costs"))]
     pub engine_tooling_costs: String,
-    #[serde(rename(deserialize = "Engine Material Cost"))]
+    #[serde(default, rename(deserialize = "Engine Material Cost"))]
     pub engine_material_cost: String,
-    #[serde(rename(deserialize = "Engine Total Cost"))]
+    #[serde(default, rename(deserialize = "Engine Total Cost"))]
     pub engine_total_cost: String,
-    #[serde(rename(deserialize = "Engine Service Cost"))]
+    #[serde(default, rename(deserialize = "Engine Service Cost"))]
     pub engine_service_cost: String,
-    #[serde(rename(deserialize = "Crankshaft Max RPM"))]
+    #[serde(default, rename(deserialize = "Crankshaft Max RPM"))]
     pub crankshaft_max_rpm: String,
-    #[serde(rename(deserialize = "Crankshaft Max Torque"))]
+    #[serde(default, rename(deserialize = "Crankshaft Max Torque"))]
     pub crankshaft_max_torque: String,
-    #[serde(rename(deserialize = "Conrods Max RPM"))]
+    #[serde(default, rename(deserialize = "Conrods Max RPM"))]
     pub conrods_max_rpm: String,
-    #[serde(rename(deserialize = "Conrods Max Torque"))]
+    #[serde(default, rename(deserialize = "Conrods Max Torque"))]
     pub conrods_max_torque: String,
-    #[serde(rename(deserialize = "Pistons Max RPM"))]
+    #[serde(default, rename(deserialize = "Pistons Max RPM"))]
     pub pistons_max_rpm: String,
-    #[serde(rename(deserialize = "Pistons Max Torque"))]
+    #[serde(default, rename(deserialize = "Pistons Max Torque"))]
     pub pistons_max_torque: String,
 }
 
 impl RawCar {
-    pub fn from_directory(path: &str) -> Result<Self> {
-        let name = path.replace("cars/", "").replace(r"cars\", "");
-        let p = format!("{}/{}_utf8.csv", path, name);
-        let mut rdr = csv::ReaderBuilder::default().has_headers(false).from_path(p)?;
-        Ok(rdr.deserialize().nth(1).ok_or(ImportError::Unknown)??)
+    /// Reads every trim exported to `<path>/<folder name>.csv`.
+    pub fn from_directory(path: &str) -> Result<Vec<Self>, ImportError> {
+        let name = std::path::Path::new(path).file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        let p = format!("{}/{}.csv", path, name);
+        let file = std::fs::File::open(&p).map_err(|_| ImportError::MissingCsv(p))?;
+        Self::from_csv_reader(file)
+    }
+
+    pub fn from_csv_reader<R: Read>(mut reader: R) -> Result<Vec<Self>, ImportError> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data).map_err(|e| ImportError::BadCsv(e.to_string()))?;
+        let text = decode::decode(&data).map_err(|e| ImportError::BadEncoding(e.to_string()))?;
+        Self::from_csv_str(&text)
+    }
+
+    pub fn from_csv_str(text: &str) -> Result<Vec<Self>, ImportError> {
+        let mut rdr = csv::ReaderBuilder::default().from_reader(text.as_bytes());
+        let headers = rdr.headers().map_err(|e| ImportError::BadCsv(e.to_string()))?.clone();
+        let mut trims = Vec::new();
+        for record in rdr.records() {
+            let record = record.map_err(|e| ImportError::BadCsv(e.to_string()))?;
+            if record.iter().all(|v| v.trim().is_empty()) { continue; }
+            let (mut raw, warnings) = columns::deserialize_row(&headers, &record)?;
+            raw.import_warnings = warnings;
+            trims.push(raw);
+        }
+        if trims.is_empty() {
+            return Err(ImportError::NoData);
+        }
+        Ok(trims)
+    }
+
+    /// The csv column names RawCar is deserialized from.
+    pub fn columns() -> &'static [&'static str] {
+        fields::struct_fields::<Self>()
     }
 
-    pub fn to_hashmap(self: Self) -> HashMap<String, String> {
-        serde_yaml::from_value(serde_yaml::to_value(&self).expect("Failed to serialize to value!")).expect("Failed to deserialize from value!")
+    pub fn to_hashmap(&self) -> Result<HashMap<String, String>> {
+        Ok(serde_yaml::from_value(serde_yaml::to_value(self)?)?)
     }
 }